@@ -0,0 +1,148 @@
+//! Player-defined aliases and macros.
+//!
+//! An alias is a name for one or more commands, e.g. `alias fire = phasers auto 500`.
+//! Placeholders turn it into a macro: `$1` to `$9` are replaced by the arguments it's
+//! given, and `$*` by all of them. Without any placeholders the arguments are tacked
+//! onto the end, so `alias fire = phasers auto` followed by `fire 500` also works.
+//!
+//! Expanded commands are fed back through `parse_args` like anything else the player types.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+
+use crate::commands;
+use crate::constants::ALIAS_DEPTH;
+use crate::io::split_commands;
+use crate::{msg, prout, say};
+
+
+pub struct Aliases {
+    pub definitions: BTreeMap<String, String>,
+    path: Option<PathBuf>,
+}
+
+impl Aliases {
+    /// Load the aliases saved by previous sessions.
+    ///
+    /// `path`: Where to load and save the aliases. If it's `None`, they aren't saved.
+    pub fn new (path: Option<PathBuf>) -> Aliases {
+        let mut aliases = Aliases { definitions: BTreeMap::new(), path: None };
+
+        if let Some(Ok(f)) = path.as_ref().map(File::open) {
+            for line in BufReader::new(f).lines().map_while(Result::ok) {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                if let Err(e) = aliases.define(line) {
+                    say!("alias.file_error", e);
+                }
+            }
+        }
+
+        aliases.path = path;  // Only save once everything's been loaded
+        aliases
+    }
+
+    /// Define an alias from a definition of the form `name = commands`.
+    pub fn define (&mut self, definition: &str) -> Result<(), String> {
+        let (name, expansion) = match definition.split_once('=') {
+            Some((n, e)) => (n.trim().to_lowercase(), e.trim().to_string()),
+            None => return Err(msg!("alias.form"))
+        };
+
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+            return Err(msg!("alias.bad_name", name))
+        }
+        if let Some(command) = commands::find(&name) {
+            return Err(msg!("alias.taken", name, command.name))
+        }
+        if split_commands(&expansion).is_empty() {
+            return Err(msg!("alias.empty"))
+        }
+
+        self.definitions.insert(name, expansion);
+        self.save();
+        Ok(())
+    }
+
+    /// Remove an alias.
+    pub fn remove (&mut self, name: &str) -> bool {
+        if self.definitions.remove(name).is_none() {
+            return false
+        }
+        self.save();
+        true
+    }
+
+    fn save (&self) {
+        let path = match &self.path {
+            Some(p) => p,
+            None => return
+        };
+
+        let mut contents = String::from("# Super Star Trek aliases and macros, one `name = commands` per line\n");
+        for (name, expansion) in &self.definitions {
+            contents.push_str(&format!("{} = {}\n", name, expansion));
+        }
+        if std::fs::write(path, contents).is_err() {
+            say!("alias.save_failed");
+        }
+    }
+
+    /// Print every alias.
+    pub fn list (&self) {
+        if self.definitions.is_empty() {
+            say!("alias.none");
+            return
+        }
+        for (name, expansion) in &self.definitions {
+            prout!("  {} = {}", name, expansion);
+        }
+    }
+
+    /// Expand any aliases in a command.
+    ///
+    /// Returns the commands to run in its place, which is just the command itself if it isn't an alias.
+    pub fn expand (&self, command: &str) -> Result<VecDeque<String>, String> {
+        self.expand_to_depth(command, 0)
+    }
+
+    fn expand_to_depth (&self, command: &str, depth: usize) -> Result<VecDeque<String>, String> {
+        let words: Vec<&str> = command.split_whitespace().collect();
+        let expansion = match words.first().and_then(|w| self.definitions.get(&w.to_lowercase())) {
+            Some(e) => e,
+            None => return Ok(VecDeque::from(vec![String::from(command)]))
+        };
+        if depth >= ALIAS_DEPTH {
+            return Err(msg!("alias.recursive", words[0]))
+        }
+
+        let args = &words[1..];
+        let mut expanded = expansion.clone();
+        let mut used = expanded.contains("$*");
+        for i in (1..=9).rev() {  // Backwards, so that $1 doesn't clobber the start of $10 should anyone write it
+            let placeholder = format!("${}", i);
+            if !expanded.contains(&placeholder) {
+                continue;
+            }
+            used = true;
+            match args.get(i - 1) {
+                Some(a) => expanded = expanded.replace(&placeholder, a),
+                None => return Err(msg!("alias.arguments", words[0], i))
+            }
+        }
+        expanded = expanded.replace("$*", &args.join(" "));
+        if !used && !args.is_empty() {
+            expanded = format!("{} {}", expanded, args.join(" "));
+        }
+
+        let mut commands: VecDeque<String> = VecDeque::new();
+        for c in split_commands(&expanded) {
+            commands.extend(self.expand_to_depth(&c, depth + 1)?);
+        }
+        Ok(commands)
+    }
+}
@@ -0,0 +1,130 @@
+//! Command-line arguments.
+//!
+//! Most of this can also be answered at the startup prompts or set with an environment
+//! variable; the arguments just let scripts and shortcuts launch a game that's ready to go.
+//! Where an argument and an environment variable disagree, the argument wins. The time combat
+//! actions take can only be changed here.
+
+use crate::structs::ActionTimes;
+
+/// The help text for `--help`.
+pub const USAGE: &str = "Usage: super-star-trek [options]
+
+Options:
+  -l, --load FILE          Load a saved game instead of starting a new one
+  -d, --difficulty LEVEL   1 or easy, 2 or normal, 3 or hard, 4 or emeritus
+  -s, --seed NUMBER        Create the galaxy from this seed (like SST_SEED)
+  -p, --password WORD      The password for self-destruct and the save file
+      --length LENGTH      How long the game lasts: short, medium or long
+      --torpedo-time SD    Stardates each torpedo takes to fire (default 0.05)
+      --phaser-time SD     Stardates a phaser volley takes (default 0.1)
+      --deathray-time SD   Stardates the deathray takes (default 0.3)
+      --no-color           Don't use colour or box drawing (like NO_COLOR)
+      --no-animation       Print everything straight away
+      --ui MODE            scroll or full (like SST_UI)
+  -h, --help               Show this help";
+
+const DIFFICULTIES: &[(&str, u8)] = &[("easy", 1), ("normal", 2), ("hard", 3), ("emeritus", 4)];
+/// Game lengths, as in the original: each unit is a week of stardates.
+const LENGTHS: &[(&str, u8)] = &[("short", 1), ("medium", 2), ("long", 4)];
+const UIS: &[&str] = &["scroll", "full"];
+
+
+/// What the game was launched with. Anything not given is `None` (or `false`).
+#[derive(Debug, Default, PartialEq)]
+pub struct Args {
+    pub load: Option<String>,
+    pub difficulty: Option<u8>,
+    pub seed: Option<u64>,
+    pub password: Option<String>,
+    pub length: Option<u8>,
+    pub torpedo_time: Option<f64>,
+    pub phaser_time: Option<f64>,
+    pub deathray_time: Option<f64>,
+    pub no_color: bool,
+    pub no_animation: bool,
+    pub ui: Option<String>,
+    pub help: bool,
+}
+
+impl Args {
+    /// Parse the arguments, not including the program's name.
+    ///
+    /// Values can be given either as the next argument or after an `=`, e.g. `--seed=1701`.
+    pub fn parse <I> (args: I) -> Result<Args, String> where I: IntoIterator<Item = String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((f, v)) if f.starts_with("--") => (String::from(f), Some(String::from(v))),
+                _ => (arg, None),
+            };
+            let mut value = |name: &str| match inline.clone().or_else(|| args.next()) {
+                Some(v) => Ok(v),
+                None => Err(format!("{} needs a {}.", flag, name)),
+            };
+
+            match flag.as_str() {
+                "-l" | "--load" => parsed.load = Some(value("file name")?),
+                "-d" | "--difficulty" => parsed.difficulty = Some(choose(&value("difficulty")?, DIFFICULTIES, "difficulty")?),
+                "-s" | "--seed" => parsed.seed = Some(value("seed")?.parse().map_err(|_| String::from("The seed must be a whole number."))?),
+                "-p" | "--password" => parsed.password = Some(value("password")?),
+                "--length" => parsed.length = Some(choose(&value("length")?, LENGTHS, "length")?),
+                "--torpedo-time" => parsed.torpedo_time = Some(stardates(&value("number of stardates")?)?),
+                "--phaser-time" => parsed.phaser_time = Some(stardates(&value("number of stardates")?)?),
+                "--deathray-time" => parsed.deathray_time = Some(stardates(&value("number of stardates")?)?),
+                "--no-color" | "--no-colour" => parsed.no_color = true,
+                "--no-animation" => parsed.no_animation = true,
+                "--ui" => {
+                    let ui = value("mode")?;
+                    if !UIS.contains(&ui.as_str()) {
+                        return Err(format!("Unknown interface \"{}\". Use \"scroll\" or \"full\".", ui))
+                    }
+                    parsed.ui = Some(ui);
+                },
+                "-h" | "--help" => parsed.help = true,
+                _ => return Err(format!("Unknown option \"{}\".", flag)),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Whether any settings for a new game were given, in which case there's no point asking about loading one.
+    pub fn new_game (&self) -> bool {
+        self.difficulty.is_some() || self.seed.is_some() || self.length.is_some()
+            || self.torpedo_time.is_some() || self.phaser_time.is_some() || self.deathray_time.is_some()
+    }
+
+    /// The combat action times for a new game, with the defaults for any that weren't given.
+    pub fn action_times (&self) -> ActionTimes {
+        let default = ActionTimes::default();
+        ActionTimes {
+            torpedo: self.torpedo_time.unwrap_or(default.torpedo),
+            phaser: self.phaser_time.unwrap_or(default.phaser),
+            deathray: self.deathray_time.unwrap_or(default.deathray),
+        }
+    }
+}
+
+
+/// Look up a setting that can be given by name or by number.
+fn choose (value: &str, table: &[(&str, u8)], what: &str) -> Result<u8, String> {
+    let value = value.to_lowercase();
+    table.iter()
+        .find(|(name, number)| *name == value || number.to_string() == value)
+        .map(|(_, number)| *number)
+        .ok_or_else(|| format!("Unknown {} \"{}\". Use one of: {}.", what, value, table.iter()
+            .map(|(name, number)| format!("{} ({})", name, number))
+            .collect::<Vec<String>>()
+            .join(", ")))
+}
+
+/// Read how long an action takes, which can't be negative.
+fn stardates (value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(time) if time.is_finite() && time >= 0.0 => Ok(time),
+        _ => Err(format!("\"{}\" isn't a number of stardates.", value)),
+    }
+}
@@ -0,0 +1,886 @@
+//! The command registry.
+//!
+//! Every command the player can type is described by a `CommandSpec` in `COMMANDS`:
+//! its name, how far it can be abbreviated, what arguments it takes, and what the
+//! crew says when those arguments don't make sense. The parser, the help system,
+//! tab completion and the parser tests are all driven from this one table.
+
+use crate::constants::DEBUG;
+use crate::io::{abbrev, get_yorn, CommandType, ControlMode};
+use crate::say;
+
+
+/// What kind of value an argument holds.
+#[derive(Debug, Clone, Copy)]
+pub enum ArgKind {
+    /// Any single word, e.g. a filename.
+    Word,
+    /// Any single word, with some suggestions for the help and tab completion.
+    Hinted(&'static [&'static str]),
+    /// A (possibly fractional) number.
+    Number,
+    /// A whole, non-negative number.
+    Integer,
+    /// One of a fixed set of `(full name, shortest abbreviation)` options.
+    Choice(&'static [(&'static str, &'static str)]),
+    /// Like `Choice`, but if the word doesn't match it's left for the next argument.
+    Flag(&'static [(&'static str, &'static str)]),
+    /// All the remaining words, as numbers.
+    Numbers,
+    /// All the remaining words, as whole numbers.
+    Integers,
+    /// All the remaining words, as a single piece of text.
+    Text,
+}
+
+/// A single argument in a command's schema.
+#[derive(Debug)]
+pub struct ArgSpec {
+    pub name: &'static str,
+    pub kind: ArgKind,
+    pub optional: bool,
+    /// The ID of what the crew says when the argument is missing or doesn't make sense.
+    pub error: &'static str,
+}
+
+/// A required argument.
+const fn req (name: &'static str, kind: ArgKind, error: &'static str) -> ArgSpec {
+    ArgSpec { name, kind, optional: false, error }
+}
+
+/// An optional argument.
+const fn opt (name: &'static str, kind: ArgKind, error: &'static str) -> ArgSpec {
+    ArgSpec { name, kind, optional: true, error }
+}
+
+
+/// A parsed argument.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Missing,
+    Word(String),
+    Number(f64),
+    Integer(u32),
+    /// The full name of the selected option.
+    Choice(&'static str),
+    Numbers(Vec<f64>),
+    Integers(Vec<i64>),
+}
+
+impl Value {
+    pub fn word (&self) -> Option<String> {
+        match self {
+            Value::Word(w) => Some(w.clone()),
+            _ => None
+        }
+    }
+
+    pub fn number (&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None
+        }
+    }
+
+    pub fn integer (&self) -> Option<u32> {
+        match self {
+            Value::Integer(i) => Some(*i),
+            _ => None
+        }
+    }
+
+    pub fn choice (&self) -> Option<&'static str> {
+        match self {
+            Value::Choice(c) => Some(c),
+            _ => None
+        }
+    }
+
+    pub fn numbers (&self) -> Vec<f64> {
+        match self {
+            Value::Numbers(n) => n.clone(),
+            _ => Vec::new()
+        }
+    }
+
+    pub fn integers (&self) -> Vec<i64> {
+        match self {
+            Value::Integers(i) => i.clone(),
+            _ => Vec::new()
+        }
+    }
+}
+
+
+/// Everything there is to know about a command.
+pub struct CommandSpec {
+    /// The command's full name.
+    pub name: &'static str,
+    /// The shortest abbreviation that's accepted. Equal to `name` if it can't be abbreviated.
+    pub least: &'static str,
+    /// Other names the command goes by, along with their shortest abbreviations.
+    pub aliases: &'static [(&'static str, &'static str)],
+    /// The ID of what the crew says if someone tries to abbreviate a command that can't be abbreviated.
+    pub abbrev_error: Option<&'static str>,
+    pub args: &'static [ArgSpec],
+    /// The ID of what the crew says if they're given too many arguments.
+    pub too_many: &'static str,
+    /// Turn the parsed arguments into a command.
+    pub build: fn(Vec<Value>) -> CommandType,
+    /// Only available in DEBUG builds.
+    pub debug_only: bool,
+    pub description: &'static str,
+    pub examples: &'static [&'static str],
+}
+
+
+const NO_ARGS: &str = "parse.no_args";
+
+const SHIELD_MODES: &[(&str, &str)] = &[("up", "u"), ("down", "d"), ("set", "s")];
+const CONTROL_MODES: &[(&str, &str)] = &[("automatic", "a"), ("manual", "m")];
+const YES_NO: &[(&str, &str)] = &[("yes", "y"), ("no", "n")];
+const SPEEDS: &[(&str, &str)] = &[("instant", "i"), ("fast", "f"), ("classic", "c")];
+
+
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "abandon",
+        least: "abandon",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Abandon,
+        debug_only: false,
+        description: "Abandon ship. You and your core crew escape in the shuttles (if they work), and the rest of the crew beam down to the nearest planet (if the transporter works).",
+        examples: &["abandon"],
+    },
+    CommandSpec {
+        name: "alias",
+        least: "al",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("definition", ArgKind::Text, "")],
+        too_many: "",
+        build: |a| CommandType::Alias(a[0].word().unwrap_or_default()),
+        debug_only: false,
+        description: "Define an alias for one or more commands, or list the aliases you've defined. `$1` to `$9` in the definition are replaced by the alias's arguments, and `$*` by all of them; without any placeholders the arguments are added to the end. Aliases are saved in `~/.sst_aliases`, which can also be edited by hand.",
+        examples: &["alias", "alias fire = phasers auto 500", "alias go = move $1 $2; srscan"],
+    },
+    CommandSpec {
+        name: "call",
+        least: "call",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::CallStarbase,
+        debug_only: false,
+        description: "Call a starbase for help. If one answers, it attempts a long-range transport of the Enterprise to the starbase's quadrant. Doesn't work if the subspace radio is damaged.",
+        examples: &["call"],
+    },
+    CommandSpec {
+        name: "capture",
+        least: "ca",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Capture,
+        debug_only: false,
+        description: "Attempt to capture a crippled Klingon ship. Not yet implemented.",
+        examples: &["capture"],
+    },
+    CommandSpec {
+        name: "cloak",
+        least: "cl",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("yes", ArgKind::Hinted(&["yes"]), "")],
+        too_many: "parse.cloak.too_many",
+        build: |a| CommandType::Cloak(a[0].word().unwrap_or_default()),
+        debug_only: false,
+        description: "Turn the cloaking device on or off. While cloaked the ship drains energy every stardate, can only be targeted by adjacent enemies, and can't use its weapons or shields. Using the cloak violates the Treaty of Algeron, so any Romulans who spot it will turn hostile. Giving `yes` skips Mr. Spock's warning.",
+        examples: &["cloak", "cl y"],
+    },
+    CommandSpec {
+        name: "commands",
+        least: "comm",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Commands,
+        debug_only: false,
+        description: "List every available command.",
+        examples: &["commands"],
+    },
+    CommandSpec {
+        name: "computer",
+        least: "comp",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("coordinates", ArgKind::Integers, "parse.computer.coordinates")],
+        too_many: "",
+        build: |a| CommandType::Computer(a[0].integers().into_iter().map(|i| i as usize).collect()),
+        debug_only: false,
+        description: "Ask the ship's computer for the course, distance, energy and time needed to reach a destination at the current warp factor. The coordinates are the quadrant's row and column, optionally followed by the sector's row and column; without a sector the computer aims for the middle of the quadrant. A damaged computer gives unreliable answers, and a badly damaged one gives none at all.",
+        examples: &["computer 3 4", "comp 3 4 5 5"],
+    },
+    CommandSpec {
+        name: "damage",
+        least: "da",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Damage,
+        debug_only: false,
+        description: "Print a damage report for every system on the ship. Damage is repaired over time, and faster while docked.",
+        examples: &["damage"],
+    },
+    CommandSpec {
+        name: "deathray",
+        least: "deathray",
+        aliases: &[],
+        abbrev_error: Some("parse.deathray.abbrev"),
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::DeathRay,
+        debug_only: false,
+        description: "Fire the experimental deathray. If it works, every enemy in the quadrant is destroyed. If it doesn't, you probably are. The odds get worse on harder difficulties and when the deathray is damaged.",
+        examples: &["deathray"],
+    },
+    CommandSpec {
+        name: "debug",
+        least: "debug",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("what", ArgKind::Hinted(&["events", "damage"]), "")],
+        too_many: "parse.debug.too_many",
+        build: |a| {
+            if !DEBUG {  // This command is only available in debug builds
+                say!("parse.debug.unavailable");
+                return CommandType::Error
+            }
+            CommandType::Debug(a[0].word().unwrap_or(String::from("invalid")))
+        },
+        debug_only: true,
+        description: "Inspect or tamper with the game's internals.",
+        examples: &["debug events"],
+    },
+    CommandSpec {
+        name: "destruct",
+        least: "destruct",
+        aliases: &[],
+        abbrev_error: Some("parse.destruct.abbrev"),
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Destruct,
+        debug_only: false,
+        description: "Activate the self-destruct. You will be asked for the password you chose at the start of the game. Anything next to the Enterprise goes up with it.",
+        examples: &["destruct"],
+    },
+    CommandSpec {
+        name: "dock",
+        least: "d",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Dock,
+        debug_only: false,
+        description: "Dock at an adjacent starbase. Not yet implemented.",
+        examples: &["dock"],
+    },
+    CommandSpec {
+        name: "emexit",
+        least: "emexit",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::EmExit,
+        debug_only: false,
+        description: "Emergency exit. Saves the game to `emsave.sst`, clears the screen and quits immediately.",
+        examples: &["emexit"],
+    },
+    CommandSpec {
+        name: "freeze",
+        least: "fr",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("filename", ArgKind::Word, "")],
+        too_many: "parse.freeze.too_many",
+        build: |a| CommandType::Freeze(a[0].word()),
+        debug_only: false,
+        description: "Save the game to a file. You will be asked for a filename if you don't give one.",
+        examples: &["freeze", "fr mygame.sst"],
+    },
+    CommandSpec {
+        name: "help",
+        least: "h",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("command", ArgKind::Word, "")],
+        too_many: "parse.help.too_many",
+        build: |a| CommandType::Help(a[0].word().unwrap_or_default()),
+        debug_only: false,
+        description: "Get help on a command. Without a command, this lists every command you can get help on.",
+        examples: &["help", "help phasers"],
+    },
+    CommandSpec {
+        name: "impulse",
+        least: "i",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[
+            opt("angle", ArgKind::Number, "parse.impulse.angle"),
+            opt("distance", ArgKind::Number, "parse.distance"),
+        ],
+        too_many: "parse.impulse.too_many",
+        build: |a| CommandType::Impulse(a[0].number(), a[1].number()),
+        debug_only: false,
+        description: "Move using the impulse (sublight) engines. The angle is in degrees, with 0 pointing right and 90 pointing up; the distance is in sectors. Impulse is slow but works while cloaked or when the warp drive is damaged.",
+        examples: &["impulse 90 2", "i 45"],
+    },
+    CommandSpec {
+        name: "log",
+        least: "lo",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("filename", ArgKind::Word, "")],
+        too_many: "parse.log.too_many",
+        build: |a| CommandType::Log(a[0].word()),
+        debug_only: false,
+        description: "Keep a transcript of the session in a file: every prompt, command and line of output, stamped with the stardate. `log off` stops it, and `log` on its own says where it's going. Please attach one to bug reports.",
+        examples: &["log", "log session.txt", "log off"],
+    },
+    CommandSpec {
+        name: "lrscan",
+        least: "lrs",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::LrScan,
+        debug_only: false,
+        description: "Perform a long-range sensor scan of the surrounding quadrants. Each quadrant is shown as a three-digit number: Klingons, starbases and stars. -1 marks the edge of the galaxy and 1000 a supernova.",
+        examples: &["lrscan"],
+    },
+    CommandSpec {
+        name: "mine",
+        least: "mi",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Mine,
+        debug_only: false,
+        description: "Mine dilithium crystals from the planet you are orbiting. Not yet implemented.",
+        examples: &["mine"],
+    },
+    CommandSpec {
+        name: "move",
+        least: "m",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[
+            opt("angle", ArgKind::Number, "parse.move.angle"),
+            opt("distance", ArgKind::Number, "parse.distance"),
+        ],
+        too_many: "parse.move.too_many",
+        build: |a| CommandType::Move(a[0].number(), a[1].number()),
+        debug_only: false,
+        description: "Move using the warp drive at the current warp factor. The angle is in degrees, with 0 pointing right and 90 pointing up; the distance is in sectors. Energy use goes up with the square of the warp factor, and doubles with the shields up. Going faster than warp 6 risks damaging the engines.",
+        examples: &["move 45 3", "m 180"],
+    },
+    CommandSpec {
+        name: "orbit",
+        least: "o",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Orbit,
+        debug_only: false,
+        description: "Orbit an adjacent planet. Not yet implemented.",
+        examples: &["orbit"],
+    },
+    CommandSpec {
+        name: "phasers",
+        least: "ph",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[
+            opt("mode", ArgKind::Hinted(&["automatic", "manual"]), ""),
+            opt("energy", ArgKind::Numbers, "parse.phasers.energy"),
+        ],
+        too_many: "",
+        build: build_phasers,
+        debug_only: false,
+        description: "Fire the phasers. The mode is `automatic`, `manual`, or just an amount of energy (which means automatic). In automatic mode the energy is split evenly between every enemy in the quadrant. In manual mode you either give a firing plan of target sectors and the energy to fire at each (row, column, energy, row, column, energy...), or are asked about each enemy in turn; leave the answer blank to skip a target. Phasers lose energy over distance, and firing more than 500 units at once may overheat them. You can't fire if it would leave less than 200 units of energy (350 with the shields up).",
+        examples: &["phasers auto 500", "ph 500", "phasers manual 5 3 400 7 8 250"],
+    },
+    CommandSpec {
+        name: "planets",
+        least: "pl",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::PlanetReport,
+        debug_only: false,
+        description: "List the planets you know about. Not yet implemented.",
+        examples: &["planets"],
+    },
+    CommandSpec {
+        name: "probe",
+        least: "pr",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[
+            opt("armed", ArgKind::Flag(&[("armed", "ar")]), ""),
+            req("mode", ArgKind::Choice(CONTROL_MODES), "parse.probe.mode"),
+            opt("coordinates", ArgKind::Integers, "parse.probe.coordinates"),
+        ],
+        too_many: "",
+        build: |a| CommandType::Probe(
+            a[0].choice().is_some(),
+            match a[1].choice() {
+                Some("manual") => ControlMode::Manual,
+                _ => ControlMode::Auto,
+            },
+            a[2].integers().into_iter().map(|i| i as i32).collect()
+        ),
+        debug_only: false,
+        description: "Launch a deep-space probe. An armed probe carries a warhead. Not yet implemented.",
+        examples: &["probe auto 3 4", "probe armed manual 1 1"],
+    },
+    CommandSpec {
+        name: "quit",
+        least: "quit",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| {
+            if DEBUG || get_yorn("Are you sure you want to quit? ") {
+                return CommandType::Quit
+            }
+            CommandType::Error
+        },
+        debug_only: false,
+        description: "Quit the game without saving.",
+        examples: &["quit"],
+    },
+    CommandSpec {
+        name: "request",
+        least: "req",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("item", ArgKind::Hinted(&["stardate", "time", "klingons", "starbases", "energy", "shields", "torpedoes", "warpfactor", "crew", "lsupport", "condition", "position"]), "")],
+        too_many: "parse.request.too_many",
+        build: |a| CommandType::Request(a[0].word().unwrap_or_default()),
+        debug_only: false,
+        description: "Print a single status value as `item: value`. The items are stardate, time, klingons, starbases, energy, shields, torpedoes, warpfactor, crew, lsupport, condition and position.",
+        examples: &["request energy", "req time"],
+    },
+    CommandSpec {
+        name: "rest",
+        least: "r",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("stardates", ArgKind::Number, "parse.rest.stardates")],
+        too_many: "parse.rest.too_many",
+        build: |a| CommandType::Rest(a[0].number().unwrap_or(f64::NAN)),
+        debug_only: false,
+        description: "Let time pass. Damage is repaired while you rest, but the Klingons don't rest either.",
+        examples: &["rest 2", "r 0.5"],
+    },
+    CommandSpec {
+        name: "score",
+        least: "sc",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Score,
+        debug_only: false,
+        description: "Show your current score.",
+        examples: &["score"],
+    },
+    CommandSpec {
+        name: "sensors",
+        least: "se",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::SensorScan,
+        debug_only: false,
+        description: "Scan a nearby planet. Not yet implemented.",
+        examples: &["sensors"],
+    },
+    CommandSpec {
+        name: "shields",
+        least: "s",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[
+            opt("mode", ArgKind::Choice(SHIELD_MODES), "parse.shields.mode"),
+            opt("energy", ArgKind::Number, "parse.shields.energy"),
+        ],
+        too_many: "parse.shields.too_many",
+        build: |a| CommandType::Shields(
+            match a[0].choice() {
+                Some(c) => c[..1].to_string(),
+                None => String::new(),
+            },
+            a[1].number().unwrap_or(f64::NAN)
+        ),
+        debug_only: false,
+        description: "Raise or lower the deflector shields, or set how much energy they hold (at most 600 units). Raising the shields doubles the energy used by the warp drive.",
+        examples: &["shields up", "s set 400"],
+    },
+    CommandSpec {
+        name: "shuttle",
+        least: "shu",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Shuttle,
+        debug_only: false,
+        description: "Launch or recover the shuttlecraft. Not yet implemented.",
+        examples: &["shuttle"],
+    },
+    CommandSpec {
+        name: "speed",
+        least: "sp",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("speed", ArgKind::Choice(SPEEDS), "parse.speed.speed")],
+        too_many: "parse.speed.too_many",
+        build: |a| CommandType::Speed(a[0].choice().unwrap_or_default().to_string()),
+        debug_only: false,
+        description: "Show or change how quickly dramatic text comes out: `instant`, `fast` or `classic`. Whatever the speed, pressing any key skips the rest of an animation.",
+        examples: &["speed", "speed instant", "sp f"],
+    },
+    CommandSpec {
+        name: "srscan",
+        least: "srs",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::SrScan,
+        debug_only: false,
+        description: "Perform a short-range sensor scan of the current quadrant, along with a summary of the ship's status. E is the Enterprise, K a Klingon, R a Romulan, B a starbase, * a star and t a Tholian. Damaged short-range sensors can only see the sectors next to the ship.",
+        examples: &["srscan"],
+    },
+    CommandSpec {
+        name: "starchart",
+        least: "star",
+        aliases: &[("map", "ma"), ("chart", "ch")],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::StarChart,
+        debug_only: false,
+        description: "Print a chart of every quadrant you know about. Unexplored quadrants are shown as ???.",
+        examples: &["starchart", "map"],
+    },
+    CommandSpec {
+        name: "status",
+        least: "st",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[],
+        too_many: NO_ARGS,
+        build: |_| CommandType::Report,
+        debug_only: false,
+        description: "Print a full status report, including any starbases you know to be under attack. This works even when the short-range sensors are damaged.",
+        examples: &["status"],
+    },
+    CommandSpec {
+        name: "thaw",
+        least: "thaw",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("filename", ArgKind::Word, "")],
+        too_many: "parse.thaw.too_many",
+        build: |a| CommandType::Thaw(a[0].word()),
+        debug_only: false,
+        description: "Load a saved game. The current game is lost.",
+        examples: &["thaw mygame.sst"],
+    },
+    CommandSpec {
+        name: "torpedoes",
+        least: "t",
+        aliases: &[("photons", "pho")],
+        abbrev_error: None,
+        args: &[
+            opt("number", ArgKind::Integer, "parse.torpedoes.number"),
+            opt("directions", ArgKind::Integers, "parse.torpedoes.directions"),
+        ],
+        too_many: "",
+        build: |a| {
+            let number = match a[0].integer() {
+                Some(n) if n > u8::MAX as u32 => {
+                    say!("weapons.torpedoes.too_many");
+                    return CommandType::Error
+                },
+                n => n.map(|n| n as u8),
+            };
+            let directions = a[1].integers();
+            if directions.iter().any(|d| *d < 0 || *d > u8::MAX as i64) {
+                say!("parse.torpedoes.directions");
+                return CommandType::Error
+            }
+            CommandType::Torpedo(number, directions.into_iter().map(|d| d as u8).collect())
+        },
+        debug_only: false,
+        description: "Fire up to three photon torpedoes. Each direction is a number from 1 to 8: 1 is right, and the numbers go counter-clockwise, so 3 is up, 5 is left and 7 is down. Torpedoes hit harder at close range.",
+        examples: &["torpedoes 1 3", "t 2 1 7"],
+    },
+    CommandSpec {
+        name: "transporter",
+        least: "tr",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("yes", ArgKind::Choice(YES_NO), "parse.transporter.yes")],
+        too_many: "parse.transporter.too_many",
+        build: |a| CommandType::Transporter(match a[0].choice() {
+            Some("yes") => 1,
+            Some(_) => 0,
+            None => 2,
+        }),
+        debug_only: false,
+        description: "Operate the transporter. Not yet implemented.",
+        examples: &["transporter"],
+    },
+    CommandSpec {
+        name: "unalias",
+        least: "un",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[req("name", ArgKind::Word, "parse.unalias.name")],
+        too_many: "parse.unalias.too_many",
+        build: |a| CommandType::Unalias(a[0].word().unwrap_or_default()),
+        debug_only: false,
+        description: "Forget an alias.",
+        examples: &["unalias fire"],
+    },
+    CommandSpec {
+        name: "warp",
+        least: "w",
+        aliases: &[],
+        abbrev_error: None,
+        args: &[opt("factor", ArgKind::Number, "parse.warp.factor")],
+        too_many: "parse.warp.too_many",
+        build: |a| CommandType::Warp(a[0].number().unwrap_or(f64::NEG_INFINITY)),
+        debug_only: false,
+        description: "Set the warp factor used by `move`, from just above 0 up to 10. Higher warp factors are faster but use far more energy, and going above warp 6 can damage the engines.",
+        examples: &["warp 6", "w 2.5"],
+    },
+];
+
+
+fn build_phasers (a: Vec<Value>) -> CommandType {
+    let energy = a[1].numbers();
+    let mode = match a[0].word() {
+        Some(m) => m,
+        None => return CommandType::Phasers(ControlMode::Undefined, f32::NAN, Vec::new())
+    };
+
+    if abbrev(&mode, "m", "manual") {
+        // Manual fire control takes a firing plan of the form `row column energy row column energy ...`
+        if !energy.len().is_multiple_of(3) {
+            say!("parse.phasers.plan");
+            return CommandType::Error
+        }
+        return CommandType::Phasers(ControlMode::Manual, f32::NAN, energy)
+    }
+
+    let total = if abbrev(&mode, "a", "automatic") {
+        energy.first().map_or(f32::NAN, |e| *e as f32)
+    } else {
+        match mode.parse::<f32>() {  // If you supply an amount of energy, it's assumed to be automatic mode
+            Ok(v) if energy.is_empty() => v,
+            Ok(_) => f32::NAN,
+            Err(_) => {
+                say!("parse.phasers.mode");
+                return CommandType::Error
+            }
+        }
+    };
+
+    if energy.len() > 1 || (!abbrev(&mode, "a", "automatic") && !energy.is_empty()) {  // Too many arguments
+        say!("parse.phasers.too_many");
+        return CommandType::Error
+    }
+    CommandType::Phasers(ControlMode::Auto, total, Vec::new())
+}
+
+
+impl CommandSpec {
+    /// Whether `word` names this command.
+    pub fn matches (&self, word: &str) -> bool {
+        if word.starts_with(self.least) && self.name.starts_with(word) {
+            return true
+        }
+        self.aliases.iter().any(|(alias, least)| word.starts_with(least) && alias.starts_with(word))
+    }
+
+    /// A usage string generated from the argument schema, e.g. `shields [up|down|set] [energy]`.
+    pub fn syntax (&self) -> String {
+        let mut syntax = String::from(self.name);
+        for arg in self.args {
+            let inner = match arg.kind {
+                ArgKind::Choice(options) | ArgKind::Flag(options) => options.iter().map(|o| o.0).collect::<Vec<&str>>().join("|"),
+                ArgKind::Numbers | ArgKind::Integers | ArgKind::Text => format!("{} ...", arg.name),
+                _ => String::from(arg.name),
+            };
+            syntax.push(' ');
+            syntax.push_str(&match arg.optional {
+                true => format!("[{}]", inner),
+                false => format!("<{}>", inner),
+            });
+        }
+        syntax
+    }
+
+    /// Parse the command's arguments according to its schema.
+    pub fn parse (&self, tokens: &[String]) -> CommandType {
+        let mut values: Vec<Value> = Vec::new();
+        let mut index = 0;
+
+        for arg in self.args {
+            let token = match tokens.get(index) {
+                Some(t) => t,
+                None => {
+                    if !arg.optional {
+                        say!(arg.error);
+                        return CommandType::Error
+                    }
+                    values.push(Value::Missing);
+                    continue;
+                }
+            };
+
+            let value = match arg.kind {
+                ArgKind::Word | ArgKind::Hinted(_) => Some(Value::Word(token.clone())),
+                ArgKind::Number => token.parse::<f64>().ok().map(Value::Number),
+                ArgKind::Integer => token.parse::<u32>().ok().map(Value::Integer),
+                ArgKind::Choice(options) | ArgKind::Flag(options) => options.iter()
+                    .find(|(full, least)| abbrev(token, least, full))
+                    .map(|o| Value::Choice(o.0)),
+                ArgKind::Numbers => tokens[index..].iter().map(|t| t.parse::<f64>().ok()).collect::<Option<Vec<f64>>>().map(Value::Numbers),
+                ArgKind::Integers => tokens[index..].iter().map(|t| t.parse::<i64>().ok()).collect::<Option<Vec<i64>>>().map(Value::Integers),
+                ArgKind::Text => Some(Value::Word(tokens[index..].join(" "))),
+            };
+
+            match (value, arg.kind) {
+                (Some(v), ArgKind::Text) => {
+                    index = tokens.len();
+                    values.push(v);
+                },
+                (Some(Value::Numbers(n)), _) => {
+                    index = tokens.len();
+                    values.push(Value::Numbers(n));
+                },
+                (Some(Value::Integers(i)), _) => {
+                    index = tokens.len();
+                    values.push(Value::Integers(i));
+                },
+                (Some(v), _) => {
+                    index += 1;
+                    values.push(v);
+                },
+                (None, ArgKind::Flag(_)) => values.push(Value::Missing),  // Not this flag; leave the word for the next argument
+                (None, _) => {
+                    say!(arg.error);
+                    return CommandType::Error
+                }
+            }
+        }
+
+        if index < tokens.len() {
+            say!(self.too_many);
+            return CommandType::Error
+        }
+
+        (self.build)(values)
+    }
+}
+
+
+/// Find the command that `word` refers to.
+pub fn find (word: &str) -> Option<&'static CommandSpec> {
+    COMMANDS.iter().find(|c| c.matches(word))
+}
+
+
+/// Find a command that `word` is an illegal abbreviation of.
+pub fn find_abbreviated (word: &str) -> Option<&'static CommandSpec> {
+    COMMANDS.iter().find(|c| c.abbrev_error.is_some() && word.len() >= 3 && c.name.starts_with(word))
+}
+
+
+
+/// Suggest tab completions for the last word of a partially-typed command line.
+pub fn completions (line: &str) -> Vec<String> {
+    let tokens: Vec<&str> = line.trim_start().split(' ').collect();
+    let current = tokens[tokens.len() - 1].to_lowercase();
+
+    if tokens.len() == 1 {
+        let mut names: Vec<String> = Vec::new();
+        for command in COMMANDS {
+            if command.debug_only && !DEBUG {
+                continue;
+            }
+            for name in std::iter::once(command.name).chain(command.aliases.iter().map(|a| a.0)) {
+                if name.starts_with(&current) {
+                    names.push(String::from(name));
+                }
+            }
+        }
+        return names
+    }
+
+    let command = match find(&tokens[0].to_lowercase()) {
+        Some(c) => c,
+        None => return Vec::new()
+    };
+
+    // Work out which argument is being typed, skipping over any flags that weren't given.
+    let mut arg_index = 0;
+    for token in tokens[1..tokens.len() - 1].iter().filter(|t| !t.is_empty()) {
+        while let Some(ArgSpec { kind: ArgKind::Flag(options), .. }) = command.args.get(arg_index) {
+            if options.iter().any(|(full, least)| abbrev(&token.to_lowercase(), least, full)) {
+                break;
+            }
+            arg_index += 1;
+        }
+        arg_index += 1;
+    }
+
+    let mut options: Vec<String> = Vec::new();
+    for arg in command.args.iter().skip(arg_index) {
+        match arg.kind {
+            ArgKind::Choice(choices) => {
+                options.extend(choices.iter().map(|c| String::from(c.0)));
+                break;
+            },
+            ArgKind::Flag(choices) => options.extend(choices.iter().map(|c| String::from(c.0))),  // Flags are optional, so offer whatever comes next too
+            ArgKind::Hinted(hints) => {
+                options.extend(hints.iter().map(|h| String::from(*h)));
+                break;
+            },
+            _ => break,
+        }
+    }
+
+    options.into_iter().filter(|o| o.starts_with(&current)).collect()
+}
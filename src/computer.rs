@@ -0,0 +1,104 @@
+//! The ship's computer.
+
+use rand::Rng;
+
+use crate::io::{get_args, input};
+use crate::movement::{calc_power, calc_time};
+use crate::{msg, say};
+
+
+/// The computer refuses to work at all once it's damaged more than this.
+pub const COMPUTER_MAX_DAMAGE: f64 = 2.0;
+
+
+/// Work out the course from one spot in the galaxy to another.
+///
+/// Locations are given as (quadrant vertical, quadrant horizontal, sector), all zero-indexed.
+///
+/// Returns the heading in degrees, as used by `move`, and the distance in sectors.
+pub fn plot_course (from: (usize, usize, usize), to: (usize, usize, usize)) -> (f64, f64) {
+    let dv = (to.0 * 10 + to.2 / 10) as f64 - (from.0 * 10 + from.2 / 10) as f64;
+    let dh = (to.1 * 10 + to.2 % 10) as f64 - (from.1 * 10 + from.2 % 10) as f64;
+
+    let mut angle = (-dv).atan2(dh).to_degrees();
+    if angle < 0.0 {
+        angle += 360.0;
+    }
+
+    (angle, (dv * dv + dh * dh).sqrt())
+}
+
+
+impl crate::structs::Universe {
+    /// Use the ship's computer to plan a trip.
+    ///
+    /// `dest`: The destination as `quadrant_vert quadrant_horiz [sector_vert sector_horiz]`,
+    /// all one-indexed. If it's empty the player is asked for it.
+    pub fn computer (&mut self, dest: Vec<usize>) {
+        if self.damage.computer > COMPUTER_MAX_DAMAGE {
+            say!("computer.inoperable");
+            return
+        }
+
+        let dest = match dest.len() {
+            0 => match get_args::<usize>(input(&msg!("computer.destination"))) {
+                Some(d) => d,
+                None => {
+                    say!("computer.invalid");
+                    return
+                }
+            },
+            _ => dest
+        };
+
+        let (qvert, qhoriz, svert, shoriz) = match dest.len() {
+            2 => (dest[0], dest[1], 5, 5),  // No sector given; aim for the middle of the quadrant
+            4 => (dest[0], dest[1], dest[2], dest[3]),
+            _ => {
+                say!("computer.specify");
+                return
+            }
+        };
+        if !(1..=8).contains(&qvert) || !(1..=8).contains(&qhoriz) || !(1..=10).contains(&svert) || !(1..=10).contains(&shoriz) {
+            say!("computer.outside");
+            return
+        }
+
+        let (mut angle, mut distance) = plot_course(
+            (self.qvert, self.qhoriz, self.sloc),
+            (qvert - 1, qhoriz - 1, (svert - 1) * 10 + shoriz - 1)
+        );
+        if distance == 0.0 {
+            say!("computer.already_there");
+            return
+        }
+
+        if self.damage.computer > 0.0 {  // A damaged computer is an unreliable computer
+            angle += self.rng.gen_range(-10.0..10.0) * self.damage.computer;
+            distance *= 1.0 + self.rng.gen_range(-0.15..0.15) * self.damage.computer;
+            say!("computer.damaged");
+        }
+
+        let bigger = angle.to_radians().sin().abs().max(angle.to_radians().cos().abs());
+        let warp_power = calc_power(&false, &distance, bigger, self.warp_factor, self.shield_status);
+        let warp_time = calc_time(&false, &distance, self.warp_factor);
+        let impulse_power = calc_power(&true, &distance, bigger, self.warp_factor, self.shield_status);
+        let impulse_time = calc_time(&true, &distance, self.warp_factor);
+
+        say!("computer.course", qvert, qhoriz, svert, shoriz);
+        say!("computer.direction", format!("{:.2}", angle));
+        say!("computer.distance", format!("{:.2}", distance));
+        say!("computer.warp", self.warp_factor, match self.shield_status {
+            true => msg!("computer.shields.up"),
+            false => msg!("computer.shields.down"),
+        }, format!("{:.2}", warp_power), format!("{:.2}", warp_time));
+        say!("computer.impulse", format!("{:.2}", impulse_power), format!("{:.2}", impulse_time));
+
+        if warp_power >= self.energy && impulse_power >= self.energy {
+            say!("computer.no_energy");
+        }
+        if warp_time >= self.time_remaining {
+            say!("computer.no_time");
+        }
+    }
+}
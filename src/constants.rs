@@ -3,4 +3,40 @@ pub const DEBUG: bool = true;  // Change to false in production
 // The date of the Treaty of Algeron, after which using a cloaking device constitutes war crimes
 pub const ALGERON: f64 = 2311.0;
 // How much time to add to the clock for each Klingon destroyed
-pub const KLINGON_KO_TIME: f64 = 1.25;
\ No newline at end of file
+pub const KLINGON_KO_TIME: f64 = 1.25;
+// How much energy the cloaking device uses per stardate
+pub const CLOAK_DRAIN: f64 = 50.0;
+// The chance per stardate that a Romulan in the same quadrant notices the cloaking device
+pub const CLOAK_DETECTION: f64 = 0.35;
+// How many stardates it takes to fire each photon torpedo, unless set with --torpedo-time
+pub const TORPEDO_TIME: f64 = 0.05;
+// How many stardates a phaser volley takes, unless set with --phaser-time
+pub const PHASER_TIME: f64 = 0.1;
+// How many stardates it takes to prepare and fire the deathray, unless set with --deathray-time
+pub const DEATHRAY_TIME: f64 = 0.3;
+// The file, in the player's home directory, that command history is saved to
+pub const HISTORY_FILE: &str = ".sst_history";
+// How many commands to remember between sessions
+pub const HISTORY_LENGTH: usize = 500;
+// The file, in the player's home directory, that aliases and macros are saved to
+pub const ALIAS_FILE: &str = ".sst_aliases";
+// How deeply aliases can refer to other aliases
+pub const ALIAS_DEPTH: usize = 10;
+// How many stardates each unit of game length is worth (short is 1, medium 2 and long 4)
+pub const DAYS_PER_LENGTH: f64 = 7.0;
+// The environment variable that selects the message catalog, e.g. `SST_LOCALE=terse`
+pub const LOCALE_VAR: &str = "SST_LOCALE";
+// The environment variable that selects the interface: `scroll` (the default) or `full` for the full-screen one
+pub const UI_VAR: &str = "SST_UI";
+// The environment variable that sets the seed for a new game, so that it can be played again
+pub const SEED_VAR: &str = "SST_SEED";
+// The environment variable that starts a transcript of the session in the file it names
+pub const TRANSCRIPT_VAR: &str = "SST_TRANSCRIPT";
+// The environment variable that logs every command of a new game to the file it names, for replaying
+pub const RECORD_VAR: &str = "SST_RECORD";
+// The environment variable that replays a game logged with `SST_RECORD` from the file it names
+pub const REPLAY_VAR: &str = "SST_REPLAY";
+// The environment variable that sets how a replay runs: `full` (the default) to play it straight through, or `step` to wait before each command
+pub const REPLAY_MODE_VAR: &str = "SST_REPLAY_MODE";
+// The environment variable that turns on plain mode (no colour or box drawing), as per https://no-color.org
+pub const NO_COLOR_VAR: &str = "NO_COLOR";
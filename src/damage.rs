@@ -1,7 +1,9 @@
 use serde::{Serialize, Deserialize};
-use rand::{Rng, thread_rng};
+use rand::Rng;
 use termion::color::{Fg, Green, Reset, Red};
 
+use crate::output::{emit, Kind, Message};
+
 
 /// Keeps track of damage.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy)]
@@ -56,7 +58,7 @@ impl Damage {
 
     /// Present the current state in a human-readable format.
     pub fn print_damage (&self) {
-        println!(
+        emit(Message::new(format!(
 "\n******** DAMAGE REPORT ********
 * == Core systems ==
 * Reactor Core:          {}
@@ -95,12 +97,11 @@ impl Damage {
     form(self.srsensors),
     form(self.cloak),
     form(self.computer),
-        )
+        )).kind(Kind::Report))
     }
 
     /// Brace for impact!
-    pub fn add_ramming_damage (&mut self, severity: f64) {
-        let mut rng = thread_rng();
+    pub fn add_ramming_damage (&mut self, severity: f64, rng: &mut impl Rng) {
 
         // Systematically damage every system of the ship.
         self.reactors      += severity * 3.0 * rng.gen::<f64>();
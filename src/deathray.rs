@@ -0,0 +1,270 @@
+//! Logic for the experimental deathray.
+//!
+//! Every possible result of firing the deathray is described by an entry in
+//! `OUTCOMES`: how likely it is, what the crew says, and what happens to the ship.
+//! The odds shift with the difficulty level and with any damage the deathray has
+//! already taken, so that a battered deathray on emeritus is a *very* bad idea.
+
+use rand::Rng;
+use supports_unicode::Stream;
+
+use crate::finish::DeathReason;
+use crate::io::{get_yorn, wait, SLOW};
+use crate::output::{self, emit, Kind, Message};
+use crate::{msg, say};
+use crate::structs::EntityType;
+
+
+/// The deathray can still be fired (at your own risk) while it's damaged less than this.
+pub const DEATHRAY_MAX_DAMAGE: f64 = 1.0;
+
+
+/// A single step of an outcome's cutscene. The text is given as message IDs, so that each
+/// locale can tell it its own way (or leave some of it out).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Line {
+    /// Print the message all at once.
+    Say(&'static str),
+    /// Print the message one character at a time.
+    Slow(&'static str),
+    /// Print an empty line.
+    Blank,
+    /// Pause for this many seconds.
+    Pause(u32),
+    /// Switch alerts (shown in red) on or off.
+    Red(bool),
+    /// Slowly print the first message if the terminal supports unicode; otherwise print the second one.
+    Unicode(&'static str, &'static str),
+}
+
+/// What firing the deathray does to the universe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Effect {
+    /// Every enemy in the quadrant is destroyed.
+    Success,
+    /// The Enterprise is destroyed.
+    Death(DeathReason),
+}
+
+/// One possible result of firing the deathray.
+#[derive(Debug)]
+pub struct Outcome {
+    /// Relative weight at difficulty 1 with an undamaged deathray.
+    pub base: f64,
+    /// Added to the weight for every difficulty level above easy.
+    pub per_difficulty: f64,
+    /// Added to the weight for every unit of damage to the deathray.
+    pub per_damage: f64,
+    pub script: &'static [Line],
+    pub effect: Effect,
+}
+
+impl Outcome {
+    /// How likely this outcome is, relative to the others.
+    pub fn weight (&self, difficulty: u8, damage: f64) -> f64 {
+        (self.base + self.per_difficulty * (difficulty.max(1) - 1) as f64 + self.per_damage * damage).max(0.0)
+    }
+}
+
+
+pub const OUTCOMES: &[Outcome] = &[
+    Outcome {
+        base: 11.0,
+        per_difficulty: -1.0,
+        per_damage: -4.0,
+        script: &[
+            Line::Slow("deathray.working"),
+        ],
+        effect: Effect::Success,
+    },
+    Outcome {
+        base: 2.0,
+        per_difficulty: 0.25,
+        per_damage: 3.0,
+        script: &[
+            Line::Slow("deathray.working"),
+            Line::Pause(4),
+            Line::Red(true),
+            Line::Slow("deathray.breach.alert"),
+            Line::Slow("deathray.breach.imminent"),
+            Line::Slow("deathray.breach.cut_off"),
+            Line::Say("deathray.breach.boom"),
+            Line::Say("deathray.breach.stars"),
+            Line::Red(false),
+        ],
+        effect: Effect::Death(DeathReason::MaximumEntropy),
+    },
+    Outcome {
+        base: 1.0,
+        per_difficulty: 0.0,
+        per_damage: 1.0,
+        script: &[
+            Line::Slow("deathray.mutation.sulu"),
+            Line::Say("deathray.mutation.uhura"),
+            Line::Say("deathray.mutation.spock"),
+            Line::Say("deathray.mutation.vulcans"),
+            Line::Say("deathray.mutation.kirk"),
+            Line::Pause(3),
+        ],
+        effect: Effect::Death(DeathReason::Transformation),
+    },
+    Outcome {
+        base: 1.0,
+        per_difficulty: 0.25,
+        per_damage: 0.0,
+        script: &[
+            Line::Slow("deathray.working"),
+            Line::Pause(5),
+            Line::Blank,
+            Line::Slow("deathray.borg.readings"),
+            Line::Slow("deathray.borg.wormhole"),
+            Line::Slow("deathray.borg.cube"),
+            Line::Slow("deathray.borg.size"),
+            Line::Pause(4),
+            Line::Blank,
+            Line::Slow("deathray.borg.hailing"),
+            Line::Slow("deathray.borg.message"),
+            Line::Pause(1),
+        ],
+        effect: Effect::Death(DeathReason::Borg),
+    },
+    Outcome {
+        base: 1.0,
+        per_difficulty: 0.0,
+        per_damage: 0.5,
+        script: &[
+            Line::Slow("deathray.tribbles"),
+            Line::Pause(1),
+        ],
+        effect: Effect::Death(DeathReason::Tribble),
+    },
+    Outcome {
+        base: 2.0,
+        per_difficulty: 0.5,
+        per_damage: 1.0,
+        script: &[
+            Line::Unicode("deathray.eldritch", "deathray.eldritch.plain"),
+        ],
+        effect: Effect::Death(DeathReason::NegativeSpaceWedgie),
+    },
+];
+
+
+/// Pick an outcome, weighted by the difficulty level and the deathray's damage.
+pub fn choose_outcome <R: Rng> (rng: &mut R, difficulty: u8, damage: f64) -> &'static Outcome {
+    let total: f64 = OUTCOMES.iter().map(|o| o.weight(difficulty, damage)).sum();
+    let mut roll = rng.gen_range(0.0..total);
+
+    for outcome in OUTCOMES {
+        let weight = outcome.weight(difficulty, damage);
+        if roll < weight {
+            return outcome
+        }
+        roll -= weight;
+    }
+
+    &OUTCOMES[OUTCOMES.len() - 1]  // Floating-point rounding; shouldn't really happen.
+}
+
+
+/// Play an outcome's cutscene.
+fn play (script: &[Line]) {
+    let mut kind = Kind::Narration;
+    for line in script {
+        let say = |text: &str| match kind {
+            Kind::Alert => Message::new(text).kind(Kind::Alert),
+            _ => Message::new(text),
+        };
+        match line {
+            Line::Say(id) | Line::Slow(id) if msg!(*id).is_empty() => {},  // Left out of this locale
+            Line::Say(id) => emit(say(&msg!(*id))),
+            Line::Slow(id) => emit(say(&msg!(*id)).slow(SLOW)),
+            Line::Blank => emit(say("")),
+            Line::Pause(seconds) => wait(*seconds),
+            Line::Red(true) => kind = Kind::Alert,
+            Line::Red(false) => kind = Kind::Narration,
+            Line::Unicode(fancy, plain) => {
+                if !output::plain() && supports_unicode::on(Stream::Stdout) {
+                    emit(say(&msg!(*fancy)).slow(SLOW));
+                } else {
+                    emit(say(&msg!(*plain)));
+                    wait(3);
+                }
+            },
+        }
+    }
+}
+
+
+impl crate::structs::Universe {
+    /// Fire the experimental death ray!
+    ///
+    /// Returns:
+    ///     - `true` if successful
+    ///     - `false` otherwise
+    pub fn deathray (&mut self) -> bool {
+        if self.damage.deathray >= DEATHRAY_MAX_DAMAGE {
+            say!("deathray.damaged");
+            return false
+        }
+        else if self.cloaked {
+            say!("deathray.cloaked");
+            return false
+        }
+        else if self.get_quadrant().search(EntityType::Klingon).is_empty()
+            && self.get_quadrant().search(EntityType::Romulan).is_empty()
+            && self.get_quadrant().search(EntityType::Tholian).is_empty()
+            && self.get_quadrant().search(EntityType::Unknown).is_empty() {
+                say!("deathray.no_enemies");
+                return false
+        }
+        else if self.energy < 100.1 {
+            say!("deathray.no_power");
+            return false
+        }
+
+        if self.damage.deathray > 0.0 {
+            say!("deathray.unrepaired");
+        }
+        if !get_yorn(&msg!("deathray.confirm")) {
+            return false
+        }
+
+        say!("deathray.as_you_wish");
+        self.add_time(self.action_times.deathray);
+        play(&[
+            Line::Slow("deathray.siren"),
+            Line::Say("deathray.scramble"),
+            Line::Say("deathray.preparations"),
+            Line::Say("deathray.ready"),
+            Line::Say("deathray.fire"),
+            Line::Slow("deathray.whirr"),
+        ]);
+        let difficulty = self.get_difficulty();
+        let outcome = choose_outcome(&mut self.rng, difficulty, self.damage.deathray);
+        play(outcome.script);
+
+        match outcome.effect {
+            Effect::Success => {
+                for entity in self.get_quadrant().entities {
+                    match entity.0 {
+                        EntityType::Klingon
+                            | EntityType::Romulan
+                            | EntityType::Unknown
+                            | EntityType::Tholian => self.kill_enemy(self.qvert, self.qhoriz, entity.1),
+                        _ => continue,
+                    }
+                }
+                if self.rng.gen::<f64>() > 0.06 {
+                    say!("deathray.inoperable");
+                    self.damage.deathray = 40.0;
+                }
+                true
+            },
+            Effect::Death(reason) => {
+                self.die(reason);
+                false  // You've got 99 problems, and the Klingons are no longer one of them.
+            },
+        }
+    }
+}
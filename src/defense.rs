@@ -1,43 +1,93 @@
+use rand::Rng;
 use crate::io::{abbrev, get_yorn, input};
-use crate::prout;
+use crate::{msg, say};
 use crate::structs::EntityType;
-use crate::constants::ALGERON;
+use crate::constants::{ALGERON, CLOAK_DRAIN, CLOAK_DETECTION};
+use crate::scans::get_vicinity;
 
 impl crate::structs::Universe {
     /// Operate the cloaking device.
     pub fn cloak (&mut self, yorn: String) {
         if self.damage.cloak > 0.0 {
-            prout!("[*Engineering*] Sir, we canna' control the cloaking device until this damage is repaired.");
+            say!("defense.cloak.damaged");
             return
         }
 
         if !self.cloaked {
-            if !abbrev(&yorn, "y", "yes") && self.stardate > ALGERON {
-                if !get_yorn("[*Mr. Spock*] Captain, using the cloaking device would violate the Treaty of Algeron. If the Romulans catch us using it, it will bring their ire upon us. Are you sure you want to use it?\n> ") {
-                    return
-                }
+            if !abbrev(&yorn, "y", "yes") && self.stardate > ALGERON && !get_yorn(&msg!("defense.cloak.treaty")) {
+                return
             }
 
-            self.cloaked = true;
-            if self.get_quadrant().search(EntityType::Romulan).len() > 0 {  // Check for Romulans
-                prout!("\nA Romulan ship has observed you using your cloaking device. From now on, all Romulan ships will be hostile towards you.");
-                self.doit(); // The Romulans are royally pissed.
+            if self.energy < CLOAK_DRAIN {
+                say!("defense.cloak.no_power");
+                return
+            }
+
+            if self.shield_status {
+                say!("defense.cloak.shields_down");
+                self.shield_status = false;
             }
+            self.cloaked = true;
+            self.romulan_detection(1.0);
         } else {
             self.cloaked = false;
         }
     }
 
 
+    /// Keep the cloaking device powered for `elapsed` stardates.
+    ///
+    /// While the cloak is up any Romulans in the quadrant might spot it.
+    pub fn run_cloak (&mut self, elapsed: f64) {
+        if !self.cloaked {
+            return
+        }
+
+        self.energy -= CLOAK_DRAIN * elapsed;
+        if self.energy < CLOAK_DRAIN {
+            say!("defense.cloak.drained");
+            self.cloaked = false;
+            return
+        }
+
+        self.romulan_detection(1.0 - (1.0 - CLOAK_DETECTION).powf(elapsed));
+    }
+
+
+    /// Give any Romulans in the quadrant a `chance` to notice that the cloaking device is in use.
+    pub fn romulan_detection (&mut self, chance: f64) {
+        if !self.cloaked || self.ididit || self.get_quadrant().search(EntityType::Romulan).is_empty() {
+            return
+        }
+
+        if self.rng.gen::<f64>() < chance {
+            say!("defense.romulan.detected");
+            self.doit(); // The Romulans are royally pissed.
+        }
+    }
+
+
+    /// Whether an enemy at `loc` can get a lock on the Enterprise.
+    ///
+    /// A cloaked ship can only be targeted from point-blank range.
+    pub fn can_be_targeted_from (&self, loc: usize) -> bool {
+        !self.cloaked || get_vicinity(self.sloc).contains(&loc)
+    }
+
+
     /// Control the deflector shields
     pub fn shields (&mut self, raw_mode: String, raw_amount: f64) {
         if self.damage.shields > 0.0 {
-            prout!("[*Tactical*] Sir, the shield control circuit thingies are broke; I can't do anything till they're repaired.");
+            say!("defense.shields.damaged");
+            return;
+        }
+        if self.cloaked {
+            say!("defense.shields.cloaked");
             return;
         }
 
-        let mode = if raw_mode == "" {
-            input("[*Tactical*] Waddya wanna do: put 'em up, take 'em down, or set the energy?\n> ")
+        let mode = if raw_mode.is_empty() {
+            input(&msg!("defense.shields.mode"))
         } else { raw_mode };
         
         if abbrev(&mode, "u", "up") {
@@ -48,10 +98,10 @@ impl crate::structs::Universe {
         }
         else if abbrev(&mode, "s", "set") {
             let amount = if raw_amount.is_nan() {
-                    match input("[*Tactical*] Whaddya wanna have me set 'em to?\n> ").parse::<f64>() {
+                    match input(&msg!("defense.shields.set_to")).parse::<f64>() {
                         Ok(x) => x,
                         Err(_) => {
-                            println!("[*Tactical*] I, like, can't understand what ya sayin'.");
+                            say!("defense.shields.nonsense");
                             return;
                         }
                     }
@@ -60,25 +110,20 @@ impl crate::structs::Universe {
             };
 
             if amount < 0.0 {
-                prout!("[*Tactical*] I can't return more energy than actually's in the capacitors.");
-                return;
+                say!("defense.shields.too_little");
             }
             else if amount > 600.0 {
-                prout!("[*Tactical*] The shields, like, can't hold more than 600 energy.");
-                return;
-            } 
+                say!("defense.shields.too_much");
+            }
             else if self.energy - (amount - self.shields) <= 0.0 {
-                prout!("[*Engineering*] Captain, we don't have that much energy available.");
-                return;
+                say!("defense.shields.no_power");
             }
             else {
                 self.energy -= amount - self.shields;
                 self.shields = amount;
             }
-        } else if mode == "" {
-            return;
-        } else {
-            prout!("[*Tactical*] Come again?");
+        } else if !mode.is_empty() {
+            say!("defense.shields.come_again");
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,225 @@
+//! A line editor for the command prompt, with history and tab completion.
+//!
+//! If stdin isn't a terminal (e.g. input is piped in), this falls back to `io::input`.
+
+use std::fs::{File, OpenOptions};
+use std::io::{stdin, stdout, BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use termion::clear;
+use termion::cursor;
+use termion::event::Key;
+use termion::input::TermRead;
+use termion::raw::IntoRawMode;
+
+use crate::commands::completions;
+use crate::constants::HISTORY_LENGTH;
+use crate::io::{input, read_input};
+use crate::replay;
+use crate::output::{self, Kind, Message};
+
+
+/// The line being edited.
+#[derive(Debug, Default)]
+pub struct Line {
+    pub chars: Vec<char>,
+    pub cursor: usize,
+}
+
+impl Line {
+    pub fn text (&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    /// Replace the whole line, leaving the cursor at the end.
+    pub fn set (&mut self, text: &str) {
+        self.chars = text.chars().collect();
+        self.cursor = self.chars.len();
+    }
+
+    pub fn insert (&mut self, c: char) {
+        self.chars.insert(self.cursor, c);
+        self.cursor += 1;
+    }
+
+    pub fn backspace (&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.chars.remove(self.cursor);
+        }
+    }
+
+    pub fn delete (&mut self) {
+        if self.cursor < self.chars.len() {
+            self.chars.remove(self.cursor);
+        }
+    }
+
+    pub fn left (&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn right (&mut self) {
+        self.cursor = (self.cursor + 1).min(self.chars.len());
+    }
+
+    /// Complete the word before the cursor.
+    ///
+    /// Returns the options if there's more than one and none of them could be filled in.
+    pub fn complete (&mut self) -> Vec<String> {
+        let before: String = self.chars[..self.cursor].iter().collect();
+        let command = before.rsplit(';').next().unwrap();  // Only the command being typed matters
+        let word = command.rsplit(' ').next().unwrap();
+
+        let options = completions(command);
+        let addition: String = match options.len() {
+            0 => return Vec::new(),
+            1 => format!("{} ", &options[0][word.len()..]),
+            _ => {
+                let mut prefix = options[0].clone();
+                for option in &options[1..] {
+                    while !option.starts_with(&prefix) {
+                        prefix.pop();
+                    }
+                }
+                if prefix.len() <= word.len() {
+                    return options
+                }
+                String::from(&prefix[word.len()..])
+            }
+        };
+
+        for c in addition.chars() {
+            self.insert(c);
+        }
+        Vec::new()
+    }
+}
+
+
+pub struct LineEditor {
+    pub history: Vec<String>,
+    path: Option<PathBuf>,
+}
+
+impl LineEditor {
+    /// Create an editor, loading the history from previous sessions.
+    ///
+    /// `path`: Where to load and save the history. If it's `None`, the history isn't saved.
+    pub fn new (path: Option<PathBuf>) -> LineEditor {
+        let mut history: Vec<String> = match path.as_ref().map(File::open) {
+            Some(Ok(f)) => BufReader::new(f).lines().map_while(Result::ok).collect(),
+            _ => Vec::new(),
+        };
+        if history.len() > HISTORY_LENGTH {
+            history.drain(..history.len() - HISTORY_LENGTH);
+        }
+
+        LineEditor { history, path }
+    }
+
+    /// Remember a command, and save it for future sessions.
+    pub fn remember (&mut self, line: &str) {
+        if line.trim().is_empty() || self.history.last().map_or(false, |l| l == line) {
+            return
+        }
+        self.history.push(String::from(line));
+
+        if let Some(path) = &self.path {
+            if self.history.len() > HISTORY_LENGTH * 2 {  // Don't let the file grow forever
+                self.history.drain(..self.history.len() - HISTORY_LENGTH);
+                let _ = std::fs::write(path, self.history.join("\n") + "\n");
+            } else if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+                let _ = writeln!(file, "{}", line);  // Losing the history isn't worth interrupting the game over
+            }
+        }
+    }
+
+    /// Read a line from the player.
+    pub fn read_line (&mut self, prompt: &str) -> String {
+        if !termion::is_tty(&stdin()) || replay::replaying() {
+            let line = read_input(prompt, true);
+            self.remember(&line);
+            return line
+        }
+
+        output::emit(Message::new(prompt).kind(Kind::Prompt).no_newline());
+        let mut out = match stdout().into_raw_mode() {
+            Ok(o) => o,
+            Err(_) => {
+                let line = input("");
+                self.remember(&line);
+                return line
+            }
+        };
+        let prompt = prompt.rsplit('\n').next().unwrap();  // Only the last line gets redrawn
+
+        let mut line = Line::default();
+        let mut browsing = self.history.len();  // Position in the history; `history.len()` is the line being typed
+        let mut draft = String::new();
+
+        for key in stdin().keys() {
+            match key {
+                Ok(Key::Char('\n')) | Ok(Key::Char('\r')) => break,
+                Ok(Key::Char('\t')) => {
+                    let options = line.complete();
+                    if !options.is_empty() {
+                        write!(out, "\r\n").unwrap();  // Leave the line being typed; it's drawn again below
+                        out.suspend_raw_mode().unwrap();  // So that the output's newlines work as usual
+                        output::emit(Message::new(options.join("  ")));  // Through the output, so the full-screen interface can fit it in
+                        out.activate_raw_mode().unwrap();
+                    }
+                },
+                Ok(Key::Char(c)) => line.insert(c),
+                Ok(Key::Backspace) => line.backspace(),
+                Ok(Key::Delete) => line.delete(),
+                Ok(Key::Left) => line.left(),
+                Ok(Key::Right) => line.right(),
+                Ok(Key::Home) | Ok(Key::Ctrl('a')) => line.cursor = 0,
+                Ok(Key::End) | Ok(Key::Ctrl('e')) => line.cursor = line.chars.len(),
+                Ok(Key::Ctrl('u')) => line.set(""),
+                Ok(Key::Up) => {
+                    if browsing > 0 {
+                        if browsing == self.history.len() {
+                            draft = line.text();
+                        }
+                        browsing -= 1;
+                        line.set(&self.history[browsing]);
+                    }
+                },
+                Ok(Key::Down) => {
+                    if browsing < self.history.len() {
+                        browsing += 1;
+                        match self.history.get(browsing) {
+                            Some(h) => line.set(h),
+                            None => line.set(&draft),
+                        }
+                    }
+                },
+                Ok(Key::Ctrl('c')) | Ok(Key::Ctrl('d')) => {
+                    line.set("");
+                    break;
+                },
+                Ok(_) => {},
+                Err(_) => break,
+            }
+
+            write!(out, "\r{}{}{}", prompt, line.text(), clear::UntilNewline).unwrap();
+            let behind = line.chars.len() - line.cursor;
+            if behind > 0 {
+                write!(out, "{}", cursor::Left(behind as u16)).unwrap();
+            }
+            out.flush().unwrap();
+        }
+
+        write!(out, "\r\n").unwrap();
+        out.flush().unwrap();
+        drop(out);  // Leave raw mode
+
+        let text = line.text();
+        crate::transcript::record_input(prompt, &text);
+        replay::record(replay::Entry::Command(text.clone()));
+        self.remember(&text);
+        text
+    }
+}
@@ -2,30 +2,30 @@ use serde::{Serialize, Deserialize};
 use termion::terminal_size;
 
 use crate::{constants::DEBUG, io::{get_yorn, input, slow_prout, SLOW, EXTRA_SLOW}};
-use rand::{Rng, thread_rng};
+use crate::output::{emit, Message};
+use crate::msg;
+use rand::Rng;
 
 
 impl crate::Universe {
     pub fn self_destruct (&mut self) {
-        if !get_yorn("\n[* Mr. Spock *] Captain, are you sure you want to activate the self destruct?\n> ") {
+        if !get_yorn(&msg!("finish.destruct.confirm")) {
             return;
         }
 
-        if input("\nPassword: ") != self.password { return };
-
-        slow_prout("[*Computer*] Self-destruct in 10 seconds.", SLOW, true);
-        print!(" "); slow_prout('9', EXTRA_SLOW, true);
-        print!("  "); slow_prout('8', EXTRA_SLOW, true);
-        print!("   "); slow_prout('7', EXTRA_SLOW, true);
-        print!("    "); slow_prout('6', EXTRA_SLOW, true);
-        print!("     "); slow_prout('5', EXTRA_SLOW, true);
-        print!("      "); slow_prout('4', EXTRA_SLOW, true);
-        print!("       "); slow_prout('3', EXTRA_SLOW, true);
-        print!("        "); slow_prout('2', EXTRA_SLOW, true);
-        print!("         "); slow_prout('1', EXTRA_SLOW, true);
-
-        if DEBUG || thread_rng().gen_range(0..5) == 0 {
-            slow_prout("           Goodbye, cruel world!\n", SLOW, true);
+        if input(&msg!("finish.destruct.password")) != self.password { return };
+
+        slow_prout(msg!("finish.destruct.countdown"), SLOW, true);
+        for i in (1..=9).rev() {
+            emit(Message::new(" ".repeat(10 - i)).no_newline());
+            slow_prout(i, EXTRA_SLOW, true);
+        }
+
+        if DEBUG || self.rng.gen_range(0..5) == 0 {
+            let goodbye = msg!("finish.destruct.goodbye");
+            if !goodbye.is_empty() {
+                slow_prout(goodbye, SLOW, true);
+            }
         }
 
         let (width, half) = match terminal_size() {  // Terminal width, half the terminal width accounting for some text
@@ -0,0 +1,110 @@
+//! The in-game help system.
+//!
+//! Everything here is generated from the command registry in `commands.rs`.
+
+use crate::commands::{find, ArgKind, COMMANDS};
+use crate::constants::DEBUG;
+use crate::output::{emit, Kind, Message};
+use crate::{prout, say};
+
+
+/// Suggest the command the player most likely meant.
+pub fn suggest (what: &str) -> Option<&'static str> {
+    let what = what.to_lowercase();
+    let mut best: Option<(&'static str, usize)> = None;
+
+    for command in COMMANDS {
+        if command.debug_only && !DEBUG {
+            continue;
+        }
+        for name in std::iter::once(command.name).chain(command.aliases.iter().map(|a| a.0)) {
+            let distance = edit_distance(&what, name);
+            if distance <= 2 && distance < what.len() && !best.is_some_and(|b| distance >= b.1) {
+                best = Some((command.name, distance));
+            }
+        }
+    }
+
+    best.map(|b| b.0)
+}
+
+
+/// The Levenshtein distance between two strings.
+fn edit_distance (a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            current.push(*[
+                previous[j + 1] + 1,
+                current[j] + 1,
+                previous[j] + (ca != *cb) as usize,
+            ].iter().min().unwrap());
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+
+/// Print help on a command.
+pub fn help (what: String) {
+    if what.is_empty() {
+        say!("help.intro");
+        say!("help.semicolons");
+        list_commands();
+        return
+    }
+
+    let topic = match find(&what.to_lowercase()) {
+        Some(t) => t,
+        None => {
+            match suggest(&what) {
+                Some(s) => say!("help.unknown.suggest", s),
+                None => say!("help.unknown"),
+            }
+            return
+        }
+    };
+
+    prout!("\n{}", topic.name.to_uppercase());
+    say!("help.syntax", topic.syntax());
+    for arg in topic.args {
+        if let ArgKind::Hinted(hints) = arg.kind {
+            prout!("  {}: {}", arg.name, hints.join(", "));
+        }
+    }
+    if topic.least == topic.name {
+        say!("help.abbreviation.none");
+    } else {
+        say!("help.abbreviation", topic.least);
+    }
+    if !topic.aliases.is_empty() {
+        say!("help.aliases", topic.aliases.iter().map(|a| format!("{} ({})", a.0, a.1)).collect::<Vec<String>>().join(", "));
+    }
+    prout!("\n{}\n", topic.description);
+    say!("help.examples");
+    for example in topic.examples {
+        prout!("  {}", example);
+    }
+}
+
+
+/// Print a list of every command.
+pub fn list_commands () {
+    say!("help.commands");
+    let names: Vec<&str> = COMMANDS.iter()
+        .filter(|c| DEBUG || !c.debug_only)
+        .map(|c| c.name)
+        .collect();
+    for row in names.chunks(4) {
+        let mut line = String::new();
+        for name in row {
+            line.push_str(&format!("  {:<14}", name));
+        }
+        emit(Message::new(line.trim_end()).kind(Kind::Report));
+    }
+}
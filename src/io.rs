@@ -1,13 +1,18 @@
+use crate::commands;
+use crate::output::{self, Kind, Message};
+use crate::replay;
+use crate::save::{self, SaveError};
+use crate::{msg, say};
 use crate::constants::DEBUG;
 use crate::structs::{Universe};
 
+use std::collections::VecDeque;
 use std::fs::File;
-use std::io::{Read, Write, stdin, stdout};
-use std::{thread, time};
+use std::path::PathBuf;
+use std::io::{Read, Write, stdin};
 use std::fmt::Debug;
 
 use clearscreen::clear;
-use serde_json::{to_string, from_str};
 
 
 pub const SLOW: u64 = 20;
@@ -16,65 +21,26 @@ pub const EXTRA_SLOW: u64 = 1000;
 
 /// A better version of println! which wraps lines by whole words 
 /// instead of characters.
+///
+/// The text goes to the current `output::Output`, which does the actual wrapping.
 #[macro_export]
-#[allow_internal_unstable(format_args_nl)]
 macro_rules! prout {
-    () => (println!());
+    () => ($crate::output::emit($crate::output::Message::new("")));
     ($($arg:tt)*) => ({
-        let raw: String = format_args_nl!($($arg)*).to_string();
-        let raw_parts: Vec<String> = raw.split(' ')    
-        .collect::<Vec<&str>>()
-        .into_iter()
-        .map(|element| String::from(element))
-        .collect();
-
-        let mut index: usize = 0;
-        let width = match termion::terminal_size() {
-            Ok(x) => x.0,
-            Err(_) => 80,  // If the terminal width is inaccessible, assume IBM standard 80 columns
-        } as usize;
-
-        for i in raw_parts.clone() {
-            if index + i.len() > width {
-                println!();
-            }
-            for j in i.chars() {
-                print!("{}", j);
-                if j == '\n' {
-                    index = 0;
-                }
-            }
-
-            if raw_parts.iter().position(|r| r==&i).unwrap() < raw_parts.len()-1 {
-                print!(" ");
-            }
-        }
+        $crate::output::emit($crate::output::Message::new(format!($($arg)*)));
     });
 }
 
 
 
 
-pub fn is_numeric (s: &String) -> bool {
-    // Check if a string is a number
-
-    for c in s.chars() {
-        if !c.is_numeric() {
-            return false
-        }
-    }
-
-    return true
-}
-
-
 pub fn get_yorn (prompt: &str) -> bool {
     let i: String = match prompt {
         "" => input("Are you sure? (y/n) "),
         _ => input(prompt)
     }.to_lowercase();
     if i.ends_with("!") {
-        prout!("Please don't shout; it hurts the crew's feelings.");
+        say!("input.shouting");
         return false;
     }
     return abbrev(&i, "y", "yes")
@@ -88,16 +54,6 @@ pub fn abbrev (what: &String, least: &str, full: &str) -> bool {
 }
 
 
-pub fn strip<U: std::clone::Clone + std::fmt::Debug> (what: &mut Vec<U>, start: usize, end: usize) -> Option<Vec<U>> {
-    //! Strip unnecessary elements from a Vec
-
-    if (what.len() < end) || start > end {  // Ensure that the requested elements can be removed
-        prout!("Oops! You're trying to strip elements that aren't there.");
-        return None
-    }
-    Some(what[start..end].to_vec())
-}
-
 fn convert_vec <T> (i: Vec<String>) -> Option<Vec<T>> 
     where T: std::str::FromStr, <T as std::str::FromStr>::Err: std::fmt::Debug {
     //! Take a Vec<String> and turn it into Vec<U>.
@@ -130,49 +86,77 @@ pub fn get_args <T> (i: String) -> Option<Vec<T>> where T: std::str::FromStr, <T
     convert_vec::<T>(raw_parts)
 }
 
+pub fn config_path (file: &str) -> PathBuf {
+    //! Where a config file lives; in the player's home directory if there is one.
+
+    match std::env::var_os("HOME") {
+        Some(home) => PathBuf::from(home).join(file),
+        None => PathBuf::from(file),
+    }
+}
+
 pub fn input(prompt: &str) -> String {
     //! A thin wrapper around std::io::stdin, meant to emulate Python's `input()` function
 
-    let input = &mut String::new();
+    read_input(prompt, false)
+}
 
-    print!("{}", prompt);
-    stdout().flush().unwrap();
-    stdin().read_line(input).unwrap();
-    return input.trim_end().to_string();
+pub fn read_input (prompt: &str, command: bool) -> String {
+    //! Read a line from the player, or from the replay if one is running.
+    //!
+    //! `command`: Whether it's a command, rather than the answer to a question. It only matters for recordings.
+
+    output::emit(Message::new(prompt).kind(Kind::Prompt).no_newline());
+    let input = match replay::next_input(command) {
+        Some(line) => {
+            prout!("{}", line);  // Show it as if it had been typed
+            line
+        },
+        None => {
+            let input = &mut String::new();
+            stdin().read_line(input).unwrap();
+            input.trim_end().to_string()
+        }
+    };
+
+    crate::transcript::record_input(prompt, &input);
+    replay::record(match command {
+        true => replay::Entry::Command(input.clone()),
+        false if is_secret(prompt) => replay::Entry::Secret,
+        false => replay::Entry::Answer(input.clone()),
+    });
+    return input;
 }
 
 
 
+/// Whether the answer to `prompt` is a password, and so should be kept out of transcripts and recordings.
+pub fn is_secret (prompt: &str) -> bool {
+    prompt.to_lowercase().contains("password")
+}
+
 pub fn slow_prout <T> (text: T, speed: u64, terminal_newline: bool) where T: ToString {
-    for i in text.to_string().chars() {
-        print!("{}", i);
-        stdout().flush().unwrap();
-        thread::sleep(time::Duration::from_millis(speed))
-    }
-    if terminal_newline {
-        print!("\n");
-    }
+    let message = Message::new(text).slow(speed);
+    output::emit(match terminal_newline {
+        true => message,
+        false => message.no_newline(),
+    });
 }
 
 
 pub fn wait (length: u32) {
     // Pause for `length` seconds
 
-    for _ in 0..length {
-        thread::sleep(time::Duration::from_millis(EXTRA_SLOW));
-    }
+    output::pause(length as u64 * EXTRA_SLOW);
 }
 
 
-pub fn thaw (filename: Option<String>) -> Option<Universe>{
-    //! Thaw a game.
-    //!
-    //! The .sst file type is as follows:
+pub fn thaw (filename: Option<String>, password: Option<String>) -> Option<Universe>{
+    //! Thaw a game. The player is asked for anything that isn't given.
     //!
-    //! (password)0x1e(json data for Universe object)
+    //! See `save` for the .sst file format.
 
     let mut save_file: File;
-    let uni: Universe;
 
     let filename = match filename {
         Some(v) => v,
@@ -181,10 +165,10 @@ pub fn thaw (filename: Option<String>) -> Option<Universe>{
 
     if filename.is_empty() {
         loop {
-            let temp = File::open(input("Save file: "));
+            let temp = File::open(input(&msg!("save.prompt")));
             match temp {
                 Ok(p) => {save_file = p; break;},
-                Err(_) => {prout!("ERROR: Unable to find save file.\n"); continue;}
+                Err(_) => {say!("save.not_found"); continue;}
             };
         }
     } else {
@@ -192,12 +176,12 @@ pub fn thaw (filename: Option<String>) -> Option<Universe>{
         match temp {
             Ok(p) => save_file = p,
             Err(_) => {
-                prout!("ERROR: Unable to find save file.\n");
+                say!("save.not_found");
                 loop {
-                    let temp = File::open(input("Save file: "));
+                    let temp = File::open(input(&msg!("save.prompt")));
                     match temp {
                         Ok(p) => {save_file = p; break;},
-                        Err(_) => {prout!("ERROR: Unable to find save file.\n"); continue;}
+                        Err(_) => {say!("save.not_found"); continue;}
                     };
                 }
             }
@@ -205,52 +189,38 @@ pub fn thaw (filename: Option<String>) -> Option<Universe>{
     }
     
 
-    let pass = input("Password: ");
+    let pass = password.unwrap_or_else(|| input(&msg!("save.password")));
     let mut enc_data = String::new();
     match save_file.read_to_string(&mut enc_data) {
         Ok(_) => {},
-        Err(_) => {eprintln!("\nERROR: The save file is corrupted."); return None}
+        Err(_) => {say!("save.error", SaveError::Corrupted); return None}
     }
 
-    let raw_parts: Vec<String> = enc_data.split("\0")
-        .collect::<Vec<&str>>()
-        .into_iter()
-        .map(|element| String::from(element))
-        .collect();
-    
-    if raw_parts[0] != pass {
-        eprintln!("That password is incorrect. Goodbye.");
-        return None
+    match save::decode(&enc_data, &pass) {
+        Ok(uni) => Some(uni),
+        Err(SaveError::WrongPassword) => {say!("save.wrong_password"); None},
+        Err(e) => {say!("save.error", e); None}
     }
-    
-    uni = match from_str(raw_parts[1].as_str()) {
-        Ok(data) => {data},
-        Err(_) => {prout!("\nERROR: The save file is corrupted."); return None}
-    };
-    
-    return Some(uni)
 }
 
 pub fn freeze (filename: Option<String>, uni: &Universe) {
     let filename = match filename {
         Some(v) => v,
-        None => input("Filename: ")
+        None => input(&msg!("save.filename"))
     };
     let mut file = match File::create(&filename) {
         Ok(f) => f,
         Err(e) => {
             if DEBUG { prout!("{}", e) }
-            prout!("Alas, it is impossible to create a file in that location.");
+            say!("save.cant_create");
             return;
         }
     };
 
-    match file.write_all((uni.password.clone() + "\0" + to_string(uni).unwrap().as_str()).as_bytes()) {
-        Ok(_) => {},
-        Err(_) => prout!("I'm sorry, but that file cannot be written to.")
+    match file.write_all(save::encode(uni).as_bytes()) {
+        Ok(_) => say!("save.created", filename),
+        Err(_) => say!("save.cant_write")
     }
-    
-    prout!("Game back-up created in {}", filename);
 }
 
 
@@ -263,440 +233,86 @@ pub fn em_exit (uni: Universe) {
         Ok(f) => f,
         Err(e) => {
             if DEBUG { prout!("{}", e) }
-            prout!("ERROR: Unable to save.");
+            say!("save.emergency_failed");
             return;
         }
     };
 
-    match file.write_all((uni.password.clone() + "\0" + to_string(&uni).unwrap().as_str()).as_bytes()) {
+    match file.write_all(save::encode(&uni).as_bytes()) {
         Ok(_) => {},
-        Err(_) => prout!("ERROR: Unable to save.")
+        Err(_) => say!("save.emergency_failed")
     }
 
-    clear().unwrap();
+    if !output::plain() {  // Clearing the screen only makes a mess of pipes and screen readers
+        clear().unwrap();
+    }
 }
 
 
-pub fn parse_args <'a> (raw_input: String) -> CommandType {
+pub fn split_commands (line: &str) -> VecDeque<String> {
+    //! Split a line of input into separate commands.
+    //!
+    //! Several commands can be given on one line by separating them with semicolons,
+    //! e.g. `shields up; move 45 3; srscan`. An `alias` command takes the rest of the line,
+    //! semicolons and all, so that aliases can be defined for more than one command.
+
+    let mut commands = VecDeque::new();
+    let mut rest = line;
+    loop {
+        let (command, after) = match rest.split_once(';') {
+            Some((c, a)) => (c, Some(a)),
+            None => (rest, None),
+        };
+        let first = command.split_whitespace().next().unwrap_or("").to_lowercase();
+        if commands::find(&first).map_or(false, |c| c.name == "alias") {
+            commands.push_back(rest.trim().to_string());
+            break;
+        }
+        if !command.trim().is_empty() {
+            commands.push_back(command.trim().to_string());
+        }
+        match after {
+            Some(a) => rest = a,
+            None => break,
+        }
+    }
+    commands
+}
+
+
+pub fn parse_args (raw_input: String) -> CommandType {
     //! Parse input
     //! 
     //! `raw_input`: The player's command input.
-    
-    let mut tokens: Vec<String> = raw_input.split(' ').map(|s| s.to_lowercase()).collect();
+    //!
+    //! The commands and their arguments are described in `commands::COMMANDS`.
+
+    let tokens: Vec<String> = raw_input.split_whitespace().map(|s| s.to_lowercase()).collect();
 
     for arg in tokens.clone() {
         if arg.ends_with("!") {
-            prout!("Please don't shout; it hurts the crew's feelings.");
+            say!("input.shouting");
             return CommandType::Error
         }
     }
 
-    if tokens[0].len() == 0 {
+    if tokens.len() == 0 {
         return CommandType::Error
     }
 
-    if tokens[0] == "abandon" {
-        return CommandType::Abandon
-    }
-    else if tokens[0] == "call" {
-        return CommandType::CallStarbase
-    }
-    else if abbrev(&tokens[0], "ca", "capture") {
-        return CommandType::Capture
-    }
-    else if abbrev(&tokens[0], "cl", "cloak") {
-        match tokens.len() {
-            1 => return CommandType::Cloak(String::from("")),
-            2 => return CommandType::Cloak(tokens[1].clone()),
-            _ => {
-                prout!("[*Engineering*] Uh... sir, have you been taking your pills lately?");
-                return CommandType::Error
-            }
-        }
-    }
-    else if abbrev(&tokens[0], "comm", "commands") {
-        return CommandType::Commands
-    }
-    else if abbrev(&tokens[0],"comp", "computer") {
-        return CommandType::Computer
-    }
-    else if tokens[0].starts_with("da") && "damage".contains(&tokens[0].as_str()) {
-        return CommandType::Damage
-    }
-    else if tokens[0].starts_with("dea") {
-        match tokens[0].as_str() {
-            "deathray" => return CommandType::DeathRay,
-            _ => {
-                prout!("Due to its awesome power (and tendency to explode in your face), the \"deathray\" command cannot be abbreviated.");
-                return CommandType::Error
-            }
-        }
-    }
-    else if tokens[0].starts_with("des") {
-        match tokens[0].as_str() {
-            "destruct" => return CommandType::Destruct,
-            _ => {
-                prout!("[*COMPUTER*] I'm sorry, but to prevent accidents Starfleet doesn't allow this command to be abbreviated.");
-            }
-        }
-    }
-    else if abbrev(&tokens[0], "d","dock") {
-        return CommandType::Dock
-    }
-    else if tokens[0].clone() == "emexit" {
-        return CommandType::EmExit
-    }
-    else if abbrev(&tokens[0], "fr", "freeze") {
-        match tokens.len() {
-            1 => return CommandType::Freeze(None),
-            2 => return CommandType::Freeze(Some(tokens[1].clone())),
-            _ => {
-                prout!("Huh?");
-                return CommandType::Error
-            }
-        }
-    }
-    else if abbrev(&tokens[0], "h", "help") {
-        match tokens.len() {
-            1 => return CommandType::Help(String::new()),
-            2 => return CommandType::Help(tokens[1].clone()),
-            _ => {
-                prout!("Hold yer horses! I can only give you help on one thing at a time.");
-                return CommandType::Error
-            }
-        }
-    }
-    else if abbrev(&tokens[0], "i", "impulse") {
-        match tokens.len() {
-            1 => return CommandType::Impulse(None, None),
-            2..=3 => {
-                let angle = match tokens[1].parse::<f64>() {
-                    Ok(a) => a,
-                    Err(_) => {
-                        prout!(r#"[*Helm*] Sir, "second to the right, turn left after the sun and then straight on till morning" isn't a valid course."#);
-                        return CommandType::Error
-                    }
-                };
-
-                return CommandType::Impulse(Some(angle), match tokens.len() {
-                    2 => None,
-                    3 => Some(match tokens[2].parse::<f64>() {
-                        Ok(v) => v,
-                        Err(_) => {
-                            prout!("[*Helm*] That isn't a distance.");
-                            return CommandType::Error
-                        }
-                    }),
-                    _ => panic!("This shouldn't be happening!")
-                })
-            },
-            _ => {
-                prout!("[*Helm*] Sir, please say that again more slowly.");
-                return CommandType::Error
-            }
-        }
-    }
-    else if tokens[0].clone() == "thaw" {
-        return CommandType::Thaw(match tokens.len() {
-            1 => Some(input("Enter save file name > ")),
-            2 => Some(tokens[1].clone()),
-            _ => {
-                prout!("Invalid arguments.");
-                return CommandType::Error
-            }
-        })
-    }
-    else if abbrev(&tokens[0],"lrs", "lrscan") {
-        return CommandType::LrScan;
-    }
-    else if abbrev(&tokens[0], "mi", "mine") {
-        return CommandType::Mine;
-    }
-    else if abbrev(&tokens[0], "m", "move") {
-        match tokens.len() {
-            1 => return CommandType::Move(None, None),
-            2..=3 => {
-                let angle = match tokens[1].parse::<f64>() {
-                    Ok(a) => a,
-                    Err(_) => {
-                        prout!(r#"[*Helm*] Sir, "second to the right, turn left after the sun and then straight on till morning" isn't a valid direction."#);
-                        return CommandType::Error
-                    }
-                };
-
-                let _x = tokens.len();
-                return CommandType::Move(Some(angle), match tokens.len() {
-                    2 => None,
-                    3 => Some(match tokens[2].parse::<f64>() {
-                        Ok(v) => v,
-                        Err(_) => {
-                            prout!("[*Helm*] That isn't a distance.");
-                            return CommandType::Error
-                        }
-                    }),
-                    _ => panic!("This shouldn't be happening!")
-                })
-            },
-            _ => {
-                prout!("[*Helm*] Sir, can you please say that again more slowly?");
-                return CommandType::Error
-            }
-        }
-    }
-    else if abbrev(&tokens[0], "o", "orbit") {
-        return CommandType::Orbit;
-    }
-    else if abbrev(&tokens[0], "ph", "phasers") {  // TODO fix so that the values get parsed correctly
-        let mut total_energy: f32 = f32::NAN;
-        let mode: ControlMode;
-        if tokens.len() > 1 {
-            mode = match &tokens[1] {
-                a if abbrev(&a, "a", "automatic") => {
-                    ControlMode::Auto
-                },
-                m if abbrev(&m, "m", "manual") => {
-                    ControlMode::Manual
-                },
-                n if is_numeric(&n) => {  // If you supply an amount of energy, it's assumed to be automatic mode
-                    total_energy = match tokens[1].parse::<f32>() {
-                        Ok(v) => v,
-                        Err(_) => {
-                            prout!("[*Fire Control*] That, like, makes no sense, captain.");
-                            return CommandType::Error
-                        }
-                    };
-                    ControlMode::Auto
-                },
-                _ => {
-                    prout!("[*Fire Control*] Pull the other one; its got bells on.");
-                    return CommandType::Error
-                }
-            };
-        } else {
-            return CommandType::Phasers(ControlMode::Undefined, total_energy);
-        }
-
-        if tokens.len() == 2 {  // If no control mode was supplied, assume automatic control and proceed accordingly.
-            return CommandType::Phasers(mode, total_energy)
-        }
-
-        if mode == ControlMode::Auto {
-            if tokens.len() == 3 {
-                if !is_numeric(&tokens[2]) {
-                    prout!("[*Fire Control*] Captain, that's, like, not a number, bro.");
-                    return CommandType::Error
-                }
-
-                total_energy = match tokens[2].parse::<f32>() {
-                    Ok(v) => v,
-                    Err(_) => {
-                        prout!("[*Fire Control*] Captain, that's, like, not a number, bro.");
-                        return CommandType::Error
-                    }
-                }
-            } else {  // Too many arguments
-                prout!("[*Fire Control*] Captain, you're, like, rambling. When trying to, like, fire in automatic fire control it would be, like, totally radical if you could just, like, give me the amount of energy to fire.");
-                return CommandType::Error
-            }
-        }
-        return CommandType::Phasers(mode, total_energy);
+    if let Some(command) = commands::find(&tokens[0]) {
+        return command.parse(&tokens[1..])
     }
-    else if abbrev(&tokens[0], "pl", "planets") {
-        return CommandType::PlanetReport
-    }
-    else if abbrev(&tokens[0], "pr", "probe") {
-        let mut armed: bool = false;
-        let mode = match &tokens[1] {
-            i if abbrev(i, "m", "manual") => ControlMode::Manual,
-            i if abbrev(i, "a", "automatic") => ControlMode::Auto,
-            i if abbrev(i, "ar", "armed") => {
-                armed = true;
-                tokens.remove(1);
-                match &tokens[1] {
-                    i if abbrev(i, "m", "manual") => ControlMode::Manual,
-                    i if abbrev(i, "a", "automatic") => ControlMode::Auto,
-                    _ => {
-                        prout!("[*Shuttle Bay*] Huh?");
-                        return CommandType::Error
-                    }
-                }
-            },
-            _ => {
-                prout!("[*Shuttle Bay*] Huh?");
-                return CommandType::Error
-            }
-        };
-        let tokens: Vec<i32> = match convert_vec(match strip(&mut tokens.clone(), 2, tokens.len() + 0) {
-            Some(t) => t.to_vec(),
-            None => Vec::new()
-            }) {
-                Some(t) => t,
-                None => {
-                    prout!("[*Shuttle Bay*] Those aren't valid destination coordinates.");
-                    return CommandType::Error
-                }
-            };
-
-        return CommandType::Probe(armed, mode, tokens)
-    }
-    else if tokens[0].clone() == "quit" {
-        if DEBUG {
-            return CommandType::Quit
-        } else {
-            if abbrev(&input("Are you sure you want to quit? "), "y", "yes") {
-                return CommandType::Quit
-            }
-        }
-            
-    }
-    else if abbrev(&tokens[0], "st", "status") {
-        return CommandType::Report
-    }
-    else if abbrev(&tokens[0], "req", "request") {
-        return CommandType::Request(tokens[1].clone())
-    }
-    else if abbrev(&tokens[0], "r", "rest") {
-        match tokens.len() {
-            1 => return CommandType::Rest(f64::NAN),
-            2 => return CommandType::Rest(match tokens[1].parse::<f64>(){
-                Ok(i) => i,
-                Err(_) => {
-                    prout!("[*Mr. Spock*] Sir, that isn't a number.");
-                    return CommandType::Error
-                }
-            }),
-            _ => {
-                prout!("[*Mr. Spock*] Sir, that is illogical.");
-                return CommandType::Error;
-            }
-        }
-    }
-    else if abbrev(&tokens[0], "sc", "score") {
-        return CommandType::Score
-    }
-    else if abbrev(&tokens[0], "se", "sensors") {
-        return CommandType::SensorScan
-    }
-    else if abbrev(&tokens[0], "s", "shields") {
-        match tokens.len() {
-            1 => return CommandType::Shields(String::new(), f64::NAN),
-            _ => return CommandType::Shields(match tokens[1].clone() {
-                u if "up".contains(&u) => "u".to_string(),
-                d if "down".contains(&d) => "d".to_string(),
-                t if "set".contains(&t) => "s".to_string(),
-                _ => {
-                    prout!("[*Tactical*] Say again, sir?");
-                    return CommandType::Error
-                }}, // End arg 1
-                match tokens.len() {
-                    2 => f64::NAN,
-                    3 => match tokens[2].parse::<f64>() {
-                        Ok(n) => n,
-                        Err(_) => {
-                            prout!("[*Tactical*] Sir, I, like, can't make out what you're saying.");
-                            return CommandType::Error
-                        }
-                    },
-                    _ => {
-                        prout!("[*Tactical*] What was that, sir?");
-                        return CommandType::Error                        
-                    }
-                } // End arg 2
-            )  // End return
-        }
-    }
-    else if abbrev(&tokens[0], "shu", "shuttle") {
-        return CommandType::Shuttle
-    }
-    else if abbrev(&tokens[0], "srs", "srscan") {
-        return CommandType::SrScan
-    }
-    else if abbrev(&tokens[0], "ma", "map") || abbrev(&tokens[0], "sta", "starchart") || abbrev(&tokens[0], "ch", "chart") {
-        return CommandType::StarChart
-    }
-    else if abbrev(&tokens[0], "t", "torpedoes") || abbrev(&tokens[0], "pho", "photons") {
-        let to_fire: Option<u8>;
-        let directions: Vec<u8>;
-        
-        match tokens.len() {
-            1 => return CommandType::Torpedo(None, Vec::new()),
-            2 => return CommandType::Torpedo(match tokens[1].parse::<u8>() {
-                    Ok(i) => Some(i),
-                    Err(_) => {
-                        prout!("[*Armory*] Huh?");
-                        return CommandType::Error
-                    }
-                }, Vec::new()),
-            _ => {
-                to_fire = match tokens[1].parse::<u8>() {
-                    Ok(i) => Some(i),
-                    Err(_) => {
-                        prout!("[*Armory*] Sir?");
-                        return CommandType::Error;
-                    }
-                };
-                tokens = match strip(&mut tokens.clone(), 2, tokens.len()+0) {
-                    Some(t) => t.to_vec(),
-                    None => Vec::new()
-                };
-                directions = match convert_vec(tokens) {
-                    Some(t) => t,
-                    None => {
-                        prout!("[*Armory*] Sir, that firing solution makes no sense!");
-                        return  CommandType::Error
-                    }
-                }
-            }
-        }
-        return CommandType::Torpedo(to_fire, directions)
-    }
-    else if abbrev(&tokens[0], "tr", "transporter") {
-        return CommandType::Transporter(match tokens.len() {
-            1 => 2,
-            2 => match &tokens[2] {
-                y if abbrev(y, "y", "yes") => 1,
-                n if abbrev(n, "n", "no") => 0,
-                _ => {
-                    prout!("[*Transporter Room*] I didn't quite catch that.");
-                    return CommandType::Error
-                }
-            },
-            _ => {
-                prout!("[*Transporter Room*] Um... would you mind saying that again sir?");
-                return CommandType::Error
-            }
-        })
-    }
-    else if abbrev(&tokens[0], "w", "warp") {
-        match tokens.len() {
-            1 => return CommandType::Warp(f64::NEG_INFINITY),
-            _ => return CommandType::Warp(match tokens[1].parse::<f64>() {
-                Ok(i) => i,
-                Err(_) => {
-                    prout!("[*Helm*] Sir, that isn't a valid warp factor.");
-                    return CommandType::Error
-                }
-            })
-        }
-    }
-    else if tokens[0] == "debug" {
-        if !crate::DEBUG {  // This command is only available in debug builds
-            prout!("I'm sorry, but this command is only available in DEBUG builds.");
-            return CommandType::Error
-        }
-
-        match tokens.len() {
-            2 => {
-                return CommandType::Debug(tokens[1].clone());
-            },
-            _ => {
-                return CommandType::Debug(String::from("invalid"))
-            }
-        }
+    if let Some(command) = commands::find_abbreviated(&tokens[0]) {
+        say!(command.abbrev_error.unwrap());
+        return CommandType::Error
     }
 
     // At this point we can assume that it isn't a valid command
-    prout!("[*Mr. Spock*] Captain, that is not a valid command.");
+    match crate::help::suggest(&tokens[0]) {
+        Some(s) => say!("parse.invalid.suggest", s),
+        None => say!("parse.invalid"),
+    }
     return CommandType::Error
 }
 
@@ -704,11 +320,12 @@ pub fn parse_args <'a> (raw_input: String) -> CommandType {
 pub enum CommandType {
     // Commands are sorted alphabetically for convenience.
     Abandon,
+    Alias(String),
     CallStarbase,
     Capture,
     Cloak(String),
     Commands,
-    Computer,
+    Computer(Vec<usize>),
     Damage,
     DeathRay,
     Destruct,
@@ -717,13 +334,14 @@ pub enum CommandType {
     Error,
     Freeze(Option<String>),
     Help(String),
+    Log(Option<String>),
     Impulse(Option<f64>, Option<f64>),
     Thaw(Option<String>),
     LrScan,
     Mine,
     Move(Option<f64>, Option<f64>),
     Orbit,
-    Phasers(ControlMode, f32),
+    Phasers(ControlMode, f32, Vec<f64>),
     PlanetReport,
     Probe(bool, ControlMode, Vec<i32>),
     Quit,
@@ -734,10 +352,12 @@ pub enum CommandType {
     SensorScan,
     Shields(String, f64),
     Shuttle,
+    Speed(String),
     SrScan,
     StarChart,
     Torpedo(Option<u8>, Vec<u8>),
     Transporter(u8),
+    Unalias(String),
     Warp(f64),
     Debug(String),
 }
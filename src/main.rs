@@ -14,6 +14,18 @@ weapons.rs - various weapons
 movement.rs - move the ship
 finish.rs - various ending conditions
 deathray.rs - logic for the experimental deathray
+computer.rs - the ship's computer
+help.rs - the in-game help system
+commands.rs - the command registry, which drives parsing, help and tab completion
+editor.rs - the line editor used at the command prompt
+aliases.rs - player-defined aliases and macros
+messages.rs - the message catalogs that crew dialogue is looked up in
+output.rs - where the game's output goes (the terminal, or memory)
+screen.rs - the optional full-screen interface
+transcript.rs - session transcripts, for bug reports
+replay.rs - recording games and playing them back
+args.rs - command-line arguments
+save.rs - the save file format, and bringing old saves up to date
 */
 
 // NOTE: This must be ran with the Nightly compiler.
@@ -32,9 +44,23 @@ mod scans;
 mod weapons;
 mod defense;
 mod enums;
+mod deathray;
+mod computer;
+mod help;
+mod commands;
+mod editor;
+mod aliases;
+mod messages;
+mod output;
+mod screen;
+mod transcript;
+mod replay;
+mod args;
+mod save;
 
 use io::{input, freeze, thaw, CommandType, em_exit, get_yorn, slow_prout, SLOW};
 use rand::Rng;
+use std::collections::VecDeque;
 use structs::Universe;
 use finish::DeathReason;
 
@@ -42,59 +68,171 @@ use crate::{constants::DEBUG, enums::Event};
 
 
 fn main() {
+    let args = match args::Args::parse(std::env::args().skip(1)) {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, args::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if args.help {
+        println!("{}", args::USAGE);
+        return
+    }
+
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        replay::record(replay::Entry::Panic(info.to_string()));
+        default_hook(info);
+    }));
+
+    if let Ok(locale) = std::env::var(constants::LOCALE_VAR) {
+        if !messages::set_locale(&locale) {
+            prout!("Unknown locale \"{}\". The available locales are: {}", locale, messages::CATALOGS.iter()
+                .map(|c| format!("{} ({})", c.locale, c.description))
+                .collect::<Vec<String>>()
+                .join(", "));
+        }
+    }
+
+    output::set_plain(args.no_color || std::env::var_os(constants::NO_COLOR_VAR).is_some() || !termion::is_tty(&std::io::stdout()));
+    if !args.no_animation && termion::is_tty(&std::io::stdin()) && termion::is_tty(&std::io::stdout()) {  // Piped runs shouldn't sleep
+        output::set_speed(output::Speed::Classic);
+    }
+
+    if let Some(path) = std::env::var_os(constants::TRANSCRIPT_VAR) {
+        start_transcript(std::path::Path::new(&path));
+    }
+
+    let screen = match args.ui.clone().map_or_else(|| std::env::var(constants::UI_VAR), Ok) {
+        Ok(ui) if ui == "full" => screen::Screen::new(),
+        Ok(ui) if ui != "scroll" => {
+            prout!("Unknown interface \"{}\". Use \"scroll\" or \"full\".", ui);
+            None
+        },
+        _ => None
+    };
+    if let Some(s) = &screen {
+        output::set_output(Box::new(s.clone()));
+    }
+
     prout!("\n=======================
 --- SUPER STAR TREK ---
 =======================\n");
     
-    if input("Load from save file? (y/n) ").to_lowercase().starts_with("y") {
-        match thaw(None) {
-            Some(u) => {match mainloop(u) {
-                Ok(_) => {},
-                Err(error) => prout!("Fatal error: {}", error)
-            }},
-            None => return
-        }
+    let uni = if let Some(path) = std::env::var_os(constants::REPLAY_VAR) {
+        start_replay(std::path::Path::new(&path), args.password)
+    } else if args.load.is_some() {
+        thaw(args.load, args.password)
+    } else if !args.new_game() && input("Load from save file? (y/n) ").to_lowercase().starts_with("y") {
+        thaw(None, args.password)
     } else {
-        let password = input("Password (used for self-destruct and save-file encryption: ");
-        let difficulty: u8;
-        loop {
+        let password = args.password.clone().unwrap_or_else(|| input("Password (used for self-destruct and save-file encryption: "));
+        let mut difficulty: u8 = args.difficulty.unwrap_or(0);
+        while difficulty == 0 {
             match input("Difficulty (1=easy, 2=normal, 3=hard, 4=emeritus): ").as_str().parse::<u8>() {
                 Err(_) => {prout!("Invalid difficulty."); continue},
                 Ok(res) => {
                     if 0 < res && res < 5 {
                         difficulty = res;
-                        break;
                     }
                 }
             };
         }
 
-        match mainloop(Universe::new(password, difficulty)) {
+        let seed = args.seed.map(|s| s.to_string()).map_or_else(|| std::env::var(constants::SEED_VAR), Ok);
+        let mut uni = match seed.map(|s| s.parse::<u64>()) {
+            Ok(Ok(seed)) => Universe::with_seed(password, difficulty, seed),
+            Ok(Err(_)) => {
+                prout!("The seed must be a whole number; picking one at random.");
+                Universe::new(password, difficulty)
+            },
+            Err(_) => Universe::new(password, difficulty),
+        };
+        if let Some(length) = args.length {
+            uni.set_length(length);
+        }
+        uni.action_times = args.action_times();
+        if let Some(path) = std::env::var_os(constants::RECORD_VAR) {
+            let path = std::path::Path::new(&path);
+            match replay::start_recording(path, &uni) {
+                Ok(_) => say!("replay.recording", path.display()),
+                Err(_) => say!("replay.record_failed", path.display()),
+            }
+        }
+        Some(uni)
+    };
+
+    if let Some(uni) = uni {
+        match mainloop(uni, screen.as_ref()) {
             Ok(_) => {},
             Err(e) => prout!("Fatal error: {}", e)
         }
     }
 
     prout!();
+    if let Some(s) = screen {
+        output::set_output(Box::new(output::Terminal));
+        s.close();
+    }
 }
 
 
-fn mainloop <'a> (mut uni: Universe) -> Result<(), &'static str> {
+fn mainloop <'a> (mut uni: Universe, screen: Option<&screen::Screen>) -> Result<(), &'static str> {
     //! The game's main execution loop
+    //!
+    //! `screen`: The full-screen interface, if it's being used, so that its panels can be kept up to date.
     
-    let mut randint = rand::thread_rng();
-
     let mut upcoming_events: Vec<enums::Event> = Vec::new();
-    upcoming_events.push(Event::Supernova(randint.gen_range(uni.stardate+1.5..uni.stardate+9.0)));
+    upcoming_events.push(Event::Supernova(uni.rng.gen_range(uni.stardate+1.5..uni.stardate+9.0)));
     if uni.get_difficulty() > 1 {
-        upcoming_events.extend_from_slice(events::gen_starbase_attack(&uni).as_ref())  // TODO: Make `when` a random value once done testing.
+        upcoming_events.extend_from_slice(events::gen_starbase_attack(&mut uni).as_ref())  // TODO: Make `when` a random value once done testing.
     }
     let mut last_time: f64;
-    let mut did_something: bool = false;  // Determines whether enemies attack. Necessary since scans etc. are a no-cost action.
+    let mut did_something: bool;  // Determines whether enemies attack. Necessary since scans etc. are a no-cost action.
+    let mut queue: VecDeque<String> = VecDeque::new();  // Commands given on the same line, separated by semicolons
+    let mut interrupted: bool;  // Whether something happened that should cancel the rest of the queue
+    let mut editor = editor::LineEditor::new(Some(io::config_path(constants::HISTORY_FILE)));
+    let mut aliases = aliases::Aliases::new(Some(io::config_path(constants::ALIAS_FILE)));
 
     loop {
         last_time = uni.stardate;
-        match io::parse_args(input("\nCommad > ")) {
+        did_something = false;
+        interrupted = false;
+
+        if let Some(s) = screen {
+            s.update(&uni);
+        }
+        transcript::set_stardate(uni.stardate);
+
+        let command = match queue.pop_front() {
+            Some(c) => {
+                prout!("\nCommad > {}", c);
+                c
+            },
+            None => {
+                queue = io::split_commands(&editor.read_line("\nCommad > "));
+                match queue.pop_front() {
+                    Some(c) => c,
+                    None => continue
+                }
+            }
+        };
+
+        let mut expanded = match aliases.expand(&command) {
+            Ok(e) => e,
+            Err(e) => {
+                prout!("{}", e);
+                queue.clear();
+                continue
+            }
+        };
+        let command = expanded.pop_front().unwrap();
+        while let Some(c) = expanded.pop_back() {  // The rest of the alias runs before anything else that was queued
+            queue.push_front(c);
+        }
+
+        match io::parse_args(command) {
             CommandType::Abandon => {
                 if get_yorn("Are you sure you want to abandon ship? ") {
                     prout!("");
@@ -103,11 +241,21 @@ fn mainloop <'a> (mut uni: Universe) -> Result<(), &'static str> {
                     uni.abandon_ship();
                 }
             },
+            CommandType::Alias(definition) => {
+                if definition.is_empty() {
+                    aliases.list();
+                } else {
+                    match aliases.define(&definition) {
+                        Ok(_) => say!("alias.defined"),
+                        Err(e) => prout!("{}", e),
+                    }
+                }
+            },
             CommandType::CallStarbase => uni.call(),
             CommandType::Capture => {},  // TODO add capturing Klingons
             CommandType::Cloak(yorn) => uni.cloak(yorn),
-            CommandType::Commands => {},  // TODO add commands list printout
-            CommandType::Computer => {},  // TODO add ship's computer
+            CommandType::Commands => help::list_commands(),
+            CommandType::Computer(dest) => uni.computer(dest),
             CommandType::Damage => uni.damage.print_damage(),
             CommandType::DeathRay => {
                 if uni.deathray() {
@@ -133,20 +281,43 @@ fn mainloop <'a> (mut uni: Universe) -> Result<(), &'static str> {
                 em_exit(uni);
                 return Ok(())
             },
-            CommandType::Error => continue,
+            CommandType::Error => {
+                if !queue.is_empty() {
+                    say!("queue.cancelled.error");
+                    queue.clear();
+                }
+                continue
+            },
             CommandType::Freeze(file) => freeze(file, &uni),
-            CommandType::Help(what) => {},  // TODO add help messages
+            CommandType::Help(what) => help::help(what),
+            CommandType::Log(file) => match file.as_deref() {
+                None => match transcript::path() {
+                    Some(p) => say!("transcript.status", p.display()),
+                    None => say!("transcript.none"),
+                },
+                Some("off") => {
+                    if transcript::stop() {
+                        say!("transcript.stopped");
+                    } else {
+                        say!("transcript.none");
+                    }
+                },
+                Some(f) => start_transcript(std::path::Path::new(f)),
+            },
             CommandType::Impulse(mode, deltas) => {
                 uni.move_it(true, mode, deltas);
                 did_something = true;
             },
-            CommandType::Thaw(file) => uni = thaw(file).unwrap(),  // TODO fix
+            CommandType::Thaw(file) => match thaw(file, None) {
+                Some(u) => uni = u,
+                None => say!("save.continuing"),  // `thaw` has already said what went wrong
+            },
             CommandType::LrScan => uni.lrscan(),
             CommandType::Mine => {},  // TODO add dilithium crystal mining
             CommandType::Move(a, d) => uni.move_it(false, a, d),
             CommandType::Orbit => {},  // TODO add planet orbiting
-            CommandType::Phasers(mode, energy) => {
-                uni.phasers(mode, energy as f64);
+            CommandType::Phasers(mode, energy, plan) => {
+                uni.phasers(mode, energy as f64, plan);
                 did_something = true;
             },
             CommandType::PlanetReport => {},
@@ -155,22 +326,39 @@ fn mainloop <'a> (mut uni: Universe) -> Result<(), &'static str> {
                 prout!("\nGoodbye.\n");
                 return Ok(())
             },
-            CommandType::Report => {},  // TODO add status reports
-            CommandType::Request(what) => {},  // TODO add requests? I don't remember what this is.
+            CommandType::Report => uni.report(),
+            CommandType::Request(what) => uni.request(what),
             CommandType::Rest(duration) => uni.rest(duration),
             CommandType::Score => uni.score.print_score(),
             CommandType::SensorScan => {},  // TODO add planet scan
             CommandType::Shields(mode, amount) => uni.shields(mode, amount),
             CommandType::Shuttle => {},  // TODO add shuttles
+            CommandType::Speed(speed) => match output::Speed::from_name(&speed) {
+                Some(s) => {
+                    output::set_speed(s);
+                    say!("speed.current", s.name());
+                },
+                None if speed.is_empty() => say!("speed.current", output::speed().name()),
+                None => say!("speed.unknown", speed),
+            },
             CommandType::SrScan => uni.srscan(),
             CommandType::StarChart => uni.starchart(),
             CommandType::Torpedo(num, deltas) => uni.torpedo(num, deltas),
             CommandType::Transporter(qubit) => {},  // TODO add transporters
+            CommandType::Unalias(name) => {
+                if !aliases.remove(&name) {
+                    say!("alias.unknown", name);
+                }
+            },
             CommandType::Warp(factor) => uni.change_warp(factor),
         }
 
         if did_something {  // The player has done a non-free action, so the Klingons get to shoot back
             for enemy in uni.get_quadrant().enemies() {
+                if !uni.can_be_targeted_from(enemy.1) {
+                    continue;  // Can't shoot what you can't see
+                }
+                interrupted = true;
                 let distance: usize = (uni.sloc/10).abs_diff(enemy.1 / 10) + (uni.sloc%10).abs_diff(enemy.1 % 10);
             }
         }
@@ -183,6 +371,9 @@ fn mainloop <'a> (mut uni: Universe) -> Result<(), &'static str> {
             break;
         }
 
+        if uni.time_remaining <= 0.0 && uni.death_reason == DeathReason::None {  // Combat takes time too, so the clock can run out mid-battle
+            uni.death_reason = DeathReason::TimeUp;
+        }
         if uni.death_reason != DeathReason::None { break; }
 
         if uni.stardate != last_time {  // Don't bother checking if no time has elapsed
@@ -192,11 +383,10 @@ fn mainloop <'a> (mut uni: Universe) -> Result<(), &'static str> {
                 match upcoming_events[e] {
                     Event::None => {},
                     Event::StarbaseAttack(begin, end, loc) => {
-                        println!("Processing starbase attack.");
                         if begin <= uni.stardate && uni.stardate <= end 
                             && uni.damage.radio <= 0.3 
                             && uni.quadrants[loc[0]][loc[1]].starbase_threatened() {  // I wish Rust would allow you to chain comparison operators.
-                                prout!("\n[*Lt. UHURA*] Captain, we just received a distress call from the starbase in quadrant {} {}. The base is under attack by Klingons, and can only hold out until stardate {:.2}.", loc[0] + 1, loc[1]+1, end);
+                                say!("events.starbase_attack", loc[0] + 1, loc[1] + 1, format!("{:.2}", end));
                                 upcoming_events.remove(e.clone());
                                 sub_event = true;
                         }
@@ -206,7 +396,7 @@ fn mainloop <'a> (mut uni: Universe) -> Result<(), &'static str> {
                             if uni.quadrants[loc[0]][loc[1]].starbase_threatened() {
                                 uni.kill_starbase(loc.clone());
                                 if uni.damage.radio <= 0.3 {
-                                    slow_prout(format!("[*Lt. UHURA*] Sir, an APB just came in from Starfleet. The Klingons have destroyed the starbase in quadrant {} {}. I'm sorry sir.", loc[0], loc[1]), SLOW, true);
+                                    slow_prout(msg!("events.starbase_destroyed", loc[0] + 1, loc[1] + 1), SLOW, true);
                                 }
                             }
                             upcoming_events.remove(e.clone());
@@ -222,10 +412,9 @@ fn mainloop <'a> (mut uni: Universe) -> Result<(), &'static str> {
                     },
                     Event::Supernova(t) => {
                         if uni.stardate >= t {
-                            let mut randint = rand::thread_rng();
                             loop {
-                                let v: usize = randint.gen_range(0..8);
-                                let h: usize = randint.gen_range(0..8);
+                                let v: usize = uni.rng.gen_range(0..8);
+                                let h: usize = uni.rng.gen_range(0..8);
 
                                 if !uni.get_other_quadrant(&v, &h).is_supernova {
                                     uni.quadrants[v][h].is_supernova = true;
@@ -239,16 +428,23 @@ fn mainloop <'a> (mut uni: Universe) -> Result<(), &'static str> {
                             upcoming_events.remove(e.clone());
                             sub_event = true;
                             let t = uni.stardate;
-                            upcoming_events.push(Event::Supernova(rand::thread_rng().gen_range(t+3.0..t+9.0)));
+                            upcoming_events.push(Event::Supernova(uni.rng.gen_range(t+3.0..t+9.0)));
                         }
                     }
                 }
-                if !sub_event {
+                if sub_event {
+                    interrupted = true;
+                } else {
                     e += 1;
                 }
                 sub_event = false;  // reset sub_event for next iteration
             }
         }
+
+        if interrupted && !queue.is_empty() {
+            say!("queue.cancelled.interrupted");
+            queue.clear();
+        }
     }
 
     prout!("\n\nThe stardate is {:.2}", uni.stardate);
@@ -338,6 +534,38 @@ fn mainloop <'a> (mut uni: Universe) -> Result<(), &'static str> {
 }
 
 
+/// Start a transcript, letting the player know how it went.
+fn start_transcript (path: &std::path::Path) {
+    match transcript::start(path) {
+        Ok(_) => say!("transcript.started", path.display()),
+        Err(_) => say!("transcript.failed", path.display()),
+    }
+}
+
+
+/// Load a recording and start replaying it, returning the universe it began with.
+///
+/// The recording doesn't hold the game's password, so the player is asked for it if it isn't given.
+fn start_replay (path: &std::path::Path, password: Option<String>) -> Option<Universe> {
+    match replay::load(path) {
+        Ok((header, entries)) => {
+            let password = password.unwrap_or_else(|| input("Password: "));
+            if !header.check_password(&password) {
+                say!("replay.wrong_password");
+                return None
+            }
+            say!("replay.started", path.display(), header.seed, header.difficulty);
+            replay::start_replay(entries, std::env::var(constants::REPLAY_MODE_VAR).map_or(false, |m| m == "step"));
+            Some(header.universe(password))
+        },
+        Err(e) => {
+            say!("replay.load_failed", path.display(), e);
+            None
+        }
+    }
+}
+
+
 fn simulate_damage (uni: &mut Universe) {
     prout!("AVAILABLE SYSTEMS:");
     prout!(" - SHIELDS");
@@ -405,6 +633,12 @@ mod tests {
 
         assert_eq!(parse_args(String::from("cl y")), CommandType::Cloak(String::from("y")));
 
+        assert_eq!(parse_args(String::from("comp 3 4 5 5")), CommandType::Computer(vec![3, 4, 5, 5]));
+        assert_eq!(parse_args(String::from("computer")), CommandType::Computer(Vec::new()));
+
+        assert_eq!(parse_args(String::from("req")), CommandType::Request(String::new()));
+        assert_eq!(parse_args(String::from("request energy")), CommandType::Request(String::from("energy")));
+
         assert_eq!(parse_args(String::from("dea")), CommandType::Error);
         assert_eq!(parse_args(String::from("deathray")), CommandType::DeathRay);
 
@@ -414,15 +648,749 @@ mod tests {
         println!("{:?}", parse_args(String::from("shields u")));
         println!("{:?}", parse_args(String::from("shield tra -110.45")));
 
+        match parse_args(String::from("phasers manual 5 3 400 7 8 250")) {
+            CommandType::Phasers(ControlMode::Manual, _, plan) => assert_eq!(plan, vec![5.0, 3.0, 400.0, 7.0, 8.0, 250.0]),
+            other => panic!("Unexpected parse result: {:?}", other)
+        }
+        assert_eq!(parse_args(String::from("phasers manual 5 3")), CommandType::Error);
+
         assert_eq!(parse_args(String::from("probe arm auto 1 1")), CommandType::Probe(true, ControlMode::Auto, vec![1, 1]));
     }
 
     #[test]
     fn test_scans () {
         let mut uni = crate::Universe::new(String::from("asdf"), 1u8);
+        uni.charted[uni.qvert][uni.qhoriz] = false;
+        uni.show_srscan();  // What the full-screen panel uses; it mustn't count as a scan
+        assert!(!uni.charted[uni.qvert][uni.qhoriz]);
         uni.srscan();
+        assert!(uni.charted[uni.qvert][uni.qhoriz]);
         uni.lrscan();
         uni.starchart();
+        uni.report();
+    }
+
+    #[test]
+    fn test_torpedo_damage () {
+        use crate::structs::EntityType;
+        use crate::weapons::calc_torpedo_damage;
+
+        // Point-blank shots should always hit harder than shots from across the quadrant.
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            assert!(calc_torpedo_damage(&mut rng, 0, 1, EntityType::Klingon) > calc_torpedo_damage(&mut rng, 0, 99, EntityType::Klingon));
+        }
+
+        // Torpedoes that destroy a Klingon go through `kill_enemy`
+        let mut uni = crate::Universe::with_seed(String::from("asdf"), 4, 1701);
+        let (target, direction) = beside_klingon(&mut uni);
+        let (klingons, time) = (uni.klingons, uni.time_remaining);
+        let mut fired = 0;
+        while uni.sector(&target) == 3 {
+            assert!(uni.torpedoes > 0, "the Klingon survived every torpedo");
+            uni.torpedo(Some(1), vec![direction]);
+            fired += 1;
+        }
+        assert_eq!(uni.klingons, klingons - 1);
+        assert!((uni.time_remaining - (time + crate::constants::KLINGON_KO_TIME - fired as f64 * uni.action_times.torpedo)).abs() < 1e-9);
+        assert_eq!(uni.sector(&target), 0);
+        assert!(uni.get_quadrant().get_entity(target).is_none());
+    }
+
+    #[test]
+    fn test_phaser_plan () {
+        use crate::msg;
+        use crate::output::{set_output, Memory};
+        use crate::structs::EntityType;
+
+        let mut uni = crate::Universe::with_seed(String::from("asdf"), 1, 1701);
+        let (target, _) = beside_klingon(&mut uni);
+        let (row, column) = ((target / 10 + 1) as f64, (target % 10 + 1) as f64);
+        // A second Klingon, so that the plan can add up to too much
+        let other = (0..100).find(|&s| uni.sector(&s) == 0).unwrap();
+        let mut klingon = uni.get_quadrant().get_entity(target).unwrap();
+        klingon.1 = other;
+        uni.quadrants[uni.qvert][uni.qhoriz].entities.push(klingon);
+        uni.quadrants[uni.qvert][uni.qhoriz].sectors[other] = 3;
+        let (other_row, other_column) = ((other / 10 + 1) as f64, (other % 10 + 1) as f64);
+        let empty = (0..100).find(|&s| uni.sector(&s) == 0).unwrap();
+        let (empty_row, empty_column) = ((empty / 10 + 1) as f64, (empty % 10 + 1) as f64);
+
+        let memory = Memory::default();
+        let terminal = set_output(Box::new(memory.clone()));
+        let (energy, stardate) = (uni.energy, uni.stardate);
+
+        // Plans that are refused don't fire anything or take any time
+        let refused = [
+            (vec![11.0, column, 100.0], msg!("weapons.phasers.outside", 11.0, column)),
+            (vec![row, 0.0, 100.0], msg!("weapons.phasers.outside", row, 0.0)),
+            (vec![row, 2.5, 100.0], msg!("weapons.phasers.outside", row, 2.5)),
+            (vec![empty_row, empty_column, 100.0], msg!("weapons.phasers.no_target", empty_row, empty_column)),
+            (vec![row, column, 100.0, row, column, 100.0], msg!("weapons.phasers.duplicate", row, column)),
+            (vec![row, column, 2900.0], msg!("weapons.phasers.no_power")),
+            (vec![row, column, 1500.0, other_row, other_column, 1500.0], msg!("weapons.phasers.no_power")),
+        ];
+        for (plan, complaint) in refused {
+            uni.phasers(ControlMode::Manual, f64::NAN, plan.clone());
+            assert_eq!(memory.take(), complaint.lines().map(String::from).collect::<Vec<String>>(), "{:?}", plan);
+            assert_eq!((uni.energy, uni.stardate), (energy, stardate));
+        }
+
+        // The plan is read back before anything is fired
+        uni.phasers(ControlMode::Manual, f64::NAN, vec![row, column, 100.0, other_row, other_column, 50.0]);
+        let lines = memory.take();
+        set_output(terminal);
+        let plan = lines.iter().position(|l| *l == msg!("weapons.phasers.plan").trim()).unwrap();
+        assert!(lines[plan + 1].starts_with(&format!(" - {} at sector {}-{}: 100.00 units fired", EntityType::Klingon, row, column)));
+        assert!(lines[plan + 2].starts_with(&format!(" - {} at sector {}-{}: 50.00 units fired", EntityType::Klingon, other_row, other_column)));
+        assert!(lines[plan + 4].contains(&format!("hit on {} at sector {}-{}.", EntityType::Klingon, row, column)));
+        assert_eq!(uni.energy, energy - 150.0);
+    }
+
+    #[test]
+    fn test_combat_time () {
+        use crate::constants::KLINGON_KO_TIME;
+
+        let mut uni = crate::Universe::with_seed(String::from("asdf"), 4, 1701);
+        let (target, direction) = beside_klingon(&mut uni);
+        uni.action_times.phaser = 0.5;
+        uni.action_times.torpedo = 0.25;
+        let moved = |uni: &crate::Universe, (stardate, time): (f64, f64), by: f64| {
+            assert!((uni.stardate - (stardate + by)).abs() < 1e-9);
+            assert!((uni.time_remaining - (time - by)).abs() < 1e-9);
+        };
+
+        let before = (uni.stardate, uni.time_remaining);
+        uni.phasers(ControlMode::Manual, f64::NAN, vec![(target / 10 + 1) as f64, (target % 10 + 1) as f64, 10.0]);
+        moved(&uni, before, 0.5);
+
+        // Every torpedo takes time, though destroying a Klingon earns some back
+        let klingons = uni.klingons;
+        let before = (uni.stardate, uni.time_remaining);
+        uni.torpedo(Some(1), vec![direction]);
+        let bonus = (klingons - uni.klingons) as f64 * KLINGON_KO_TIME;
+        moved(&uni, (before.0, before.1 + bonus), 0.25);
+
+        // Refusing to fire costs nothing
+        let before = (uni.stardate, uni.time_remaining);
+        uni.phasers(ControlMode::Manual, f64::NAN, vec![11.0, 1.0, 10.0]);
+        moved(&uni, before, 0.0);
+    }
+
+    /// Move the Enterprise into a quadrant with a Klingon, right next to it. Returns where the Klingon is and the torpedo direction that hits it.
+    fn beside_klingon (uni: &mut crate::Universe) -> (usize, u8) {
+        use crate::structs::EntityType;
+
+        for vert in 0..8 {
+            for horiz in 0..8 {
+                for (_, klingon, _, _) in uni.get_other_quadrant(&vert, &horiz).search(EntityType::Klingon) {
+                    // Directions 1 and 5 fire along the row, 7 and 3 along the column
+                    let spots = [(klingon % 10 > 0, klingon.wrapping_sub(1), 1), (klingon % 10 < 9, klingon + 1, 5), (klingon >= 10, klingon.wrapping_sub(10), 7), (klingon < 90, klingon + 10, 3)];
+                    if let Some(&(_, sloc, direction)) = spots.iter().find(|s| s.0 && uni.quadrants[vert][horiz].sectors[s.1] == 0) {
+                        uni.quadrants[uni.qvert][uni.qhoriz].sectors[uni.sloc] = 0;
+                        uni.qvert = vert;
+                        uni.qhoriz = horiz;
+                        uni.sloc = sloc;
+                        uni.quadrants[vert][horiz].sectors[sloc] = 8;
+                        return (klingon, direction)
+                    }
+                }
+            }
+        }
+        panic!("There are no Klingons to shoot at.")
+    }
+
+    #[test]
+    fn test_seed () {
+        use crate::output::{set_output, Memory};
+        use rand::Rng;
+
+        let terminal = set_output(Box::new(Memory::default()));
+        let play = |seed: u64| {
+            let mut uni = crate::Universe::with_seed(String::from("asdf"), 3u8, seed);
+            uni.move_it(false, Some(45.0), Some(3.0));
+            uni.phasers(crate::io::ControlMode::Auto, 500.0, Vec::new());
+            uni.rest(0.5);
+            serde_json::to_string(&uni).unwrap()
+        };
+        let (first, second, other) = (play(1701), play(1701), play(1864));
+        set_output(terminal);
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+
+        // The generator's state is saved, so a loaded game carries on exactly where it left off.
+        let mut saved: crate::Universe = serde_json::from_str(&first).unwrap();
+        let mut loaded: crate::Universe = serde_json::from_str(&first).unwrap();
+        assert_eq!(saved.seed, 1701);
+        assert_eq!(saved.rng.gen::<u64>(), loaded.rng.gen::<u64>());
+    }
+
+    #[test]
+    fn test_deathray_outcomes () {
+        use rand::{rngs::StdRng, SeedableRng};
+        use crate::deathray::{choose_outcome, Effect, Line, OUTCOMES};
+
+        let success = OUTCOMES.iter().find(|o| o.effect == Effect::Success).unwrap();
+        assert!(success.weight(1, 0.0) > success.weight(4, 0.0));
+        assert!(success.weight(1, 0.0) > success.weight(1, 0.5));
+        for outcome in OUTCOMES {
+            assert!(outcome.weight(4, 100.0) >= 0.0);
+            for line in outcome.script {
+                let ids = match line {
+                    Line::Say(id) | Line::Slow(id) => vec![*id],
+                    Line::Unicode(fancy, plain) => vec![*fancy, *plain],
+                    _ => vec![],
+                };
+                for id in ids {
+                    assert!(crate::messages::CATALOGS[0].get(id).is_some(), "{} isn't in the English catalog", id);
+                }
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(1701);
+        for _ in 0..100 {
+            // A badly damaged deathray should never work.
+            assert_ne!(choose_outcome(&mut rng, 4, 3.0).effect, Effect::Success);
+        }
+    }
+
+    #[test]
+    fn test_cloak () {
+        let mut uni = crate::Universe::new(String::from("asdf"), 1u8);
+        uni.cloaked = true;
+        uni.sloc = 44;
+
+        assert!(uni.can_be_targeted_from(45));
+        assert!(!uni.can_be_targeted_from(47));
+
+        let energy = uni.energy;
+        uni.add_time(2.0);
+        assert_eq!(uni.energy, energy - 2.0 * crate::constants::CLOAK_DRAIN);
+
+        uni.energy = 10.0;
+        uni.add_time(0.5);
+        assert!(!uni.cloaked);
+    }
+
+    #[test]
+    fn test_plot_course () {
+        use crate::computer::plot_course;
+
+        assert_eq!(plot_course((0, 0, 0), (0, 0, 5)), (0.0, 5.0));  // Due east
+        assert_eq!(plot_course((1, 0, 0), (0, 0, 0)), (90.0, 10.0));  // One quadrant north
+        let (angle, distance) = plot_course((0, 0, 0), (1, 1, 0));
+        assert!((angle - 315.0).abs() < 1e-9);
+        assert!((distance - 200.0f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_request () {
+        let mut uni = crate::Universe::new(String::from("asdf"), 1u8);
+        uni.energy = 1234.5;
+        uni.qvert = 2; uni.qhoriz = 3; uni.sloc = 45;
+
+        assert_eq!(uni.request_value("energy"), Some(("energy", String::from("1234.50"))));
+        assert_eq!(uni.request_value("e"), Some(("energy", String::from("1234.50"))));
+        assert_eq!(uni.request_value("torp"), Some(("torpedoes", String::from("10"))));
+        assert_eq!(uni.request_value("position"), Some(("position", String::from("3 4 5 6"))));
+        assert_eq!(uni.request_value("bogus"), None);
+    }
+
+    #[test]
+    fn test_split_commands () {
+        use crate::io::split_commands;
+
+        assert_eq!(split_commands("shields up; move 45 3; srscan"), vec!["shields up", "move 45 3", "srscan"]);
+        assert_eq!(split_commands("srscan"), vec!["srscan"]);
+        assert_eq!(split_commands(" ;; lrscan ; "), vec!["lrscan"]);
+        assert!(split_commands("").is_empty());
+        assert_eq!(split_commands("al go = move $1 $2; srscan"), vec!["al go = move $1 $2; srscan"]);
+    }
+
+    #[test]
+    fn test_line_editor () {
+        use crate::commands::completions;
+        use crate::editor::{Line, LineEditor};
+
+        assert_eq!(completions("sh"), vec!["shields", "shuttle"]);
+        assert_eq!(completions("shields "), vec!["up", "down", "set"]);
+        assert_eq!(completions("ph a"), vec!["automatic"]);
+        assert_eq!(completions("probe "), vec!["armed", "automatic", "manual"]);
+        assert_eq!(completions("probe armed m"), vec!["manual"]);
+        assert!(completions("move ").is_empty());
+
+        let mut line = Line::default();
+        line.set("shi");
+        assert!(line.complete().is_empty());
+        assert_eq!(line.text(), "shields ");
+        line.set("srscan; s");
+        assert_eq!(line.complete().len(), 8);  // Everything starting with s
+        line.set("phasers m");
+        line.complete();
+        assert_eq!(line.text(), "phasers manual ");
+
+        line.set("mve");
+        line.left();
+        line.left();
+        line.insert('o');
+        assert_eq!(line.text(), "move");
+        line.backspace();
+        line.delete();
+        assert_eq!(line.text(), "me");
+
+        let mut editor = LineEditor::new(None);
+        editor.remember("srscan");
+        editor.remember("srscan");
+        editor.remember("  ");
+        editor.remember("lrscan");
+        assert_eq!(editor.history, vec!["srscan", "lrscan"]);
+    }
+
+    #[test]
+    fn test_aliases () {
+        use crate::aliases::Aliases;
+        use crate::io::split_commands;
+
+        let mut aliases = Aliases::new(None);
+        assert!(aliases.define("fire = phasers auto 500").is_ok());
+        assert!(aliases.define("go = move $1 $2; srscan").is_ok());
+        assert!(aliases.define("shoot = phasers auto").is_ok());
+        assert!(aliases.define("loop = loop").is_ok());
+        assert!(aliases.define("s = srscan").is_err());  // Already an abbreviation for shields
+        assert!(aliases.define("nothing = ;").is_err());
+        assert!(aliases.define("no equals sign").is_err());
+
+        assert_eq!(aliases.expand("fire").unwrap(), vec!["phasers auto 500"]);
+        assert_eq!(aliases.expand("go 45 3").unwrap(), vec!["move 45 3", "srscan"]);
+        assert_eq!(aliases.expand("shoot 300").unwrap(), vec!["phasers auto 300"]);
+        assert_eq!(aliases.expand("srscan").unwrap(), vec!["srscan"]);
+        assert!(aliases.expand("go 45").is_err());
+        assert!(aliases.expand("loop").is_err());
+
+        assert_eq!(parse_args(String::from("alias fire = phasers auto 500")), CommandType::Alias(String::from("fire = phasers auto 500")));
+        assert_eq!(parse_args(aliases.expand("fire").unwrap()[0].clone()), CommandType::Phasers(ControlMode::Auto, 500.0, Vec::new()));
+
+        assert!(aliases.remove("fire"));
+        assert!(!aliases.remove("fire"));
+
+        // Defined at the prompt, the whole macro has to survive the line being split into commands
+        let mut aliases = Aliases::new(None);
+        let mut queue = split_commands("srscan; alias go = move $1 $2; srscan");
+        assert_eq!(queue.pop_front().unwrap(), "srscan");
+        assert_eq!(queue.len(), 1);
+        match parse_args(queue.pop_front().unwrap()) {
+            CommandType::Alias(definition) => assert!(aliases.define(&definition).is_ok()),
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(aliases.expand("go 45 3").unwrap(), vec!["move 45 3", "srscan"]);
+    }
+
+    #[test]
+    fn test_messages () {
+        use crate::messages::{fill, get, set_locale, CATALOGS};
+        use crate::msg;
+
+        let placeholders = |text: &str| (0..10).filter(|i| text.contains(&format!("{{{}}}", i))).collect::<Vec<usize>>();
+        for catalog in &CATALOGS[1..] {
+            for (id, text) in catalog.messages {
+                let english = CATALOGS[0].get(id);
+                assert!(english.is_some(), "{} isn't in the English catalog", id);
+                assert!(placeholders(text).iter().all(|p| placeholders(english.unwrap()).contains(p)), "{}", id);
+            }
+        }
+
+        assert_eq!(fill("Sector {0} {1}", &[String::from("3"), String::from("4")]), "Sector 3 4");
+        assert_eq!(msg!("weapons.phasers.outside", 3, 11), "[*Tactical*] Sector 3 11 is, like, totally outside the quadrant, dude.");
+        assert_eq!(get("no.such.message"), "no.such.message");
+
+        assert!(!set_locale("klingon"));
+        assert!(set_locale("terse"));
+        assert_eq!(get("weapons.phasers.no_targets"), "No targets.");
+        assert!(set_locale("en"));
+    }
+
+    #[test]
+    fn test_output () {
+        use crate::output::{emit, set_output, Kind, Memory, Message};
+        use crate::prout;
+
+        let memory = Memory::default();
+        let terminal = set_output(Box::new(memory.clone()));
+
+        prout!("\n[*Mr. Spock*] Fascinating.");
+        emit(Message::new("Torpedo hits Klingon.").at(23));
+        let mut uni = crate::Universe::new(String::from("password"), 1u8);
+        uni.srscan();
+
+        set_output(terminal);
+
+        let messages = memory.messages.borrow();
+        assert_eq!(messages[0].kind, Kind::Dialogue);
+        assert_eq!(messages[0].speaker.as_deref(), Some("Mr. Spock"));
+        assert_eq!(messages[0].text, "\nFascinating.");
+        assert_eq!(messages[0].full_text(), "\n[*Mr. Spock*] Fascinating.");
+        assert_eq!(messages[1].sector, Some((3, 4)));
+        assert_eq!(messages[2].kind, Kind::Scan);
+        assert_eq!(messages.len(), 2 + 13);  // Header, top border, ten rows and bottom border
+        assert!(memory.text().starts_with("\n[*Mr. Spock*] Fascinating.\nTorpedo hits Klingon.\n"));
+    }
+
+    #[test]
+    fn test_plain () {
+        use crate::output::{plain_text, set_output, set_plain, Memory};
+        use termion::color::{Fg, Red, Reset};
+
+        assert_eq!(plain_text(&format!("  ┏━━┓ {}RED{} ┃", Fg(Red), Fg(Reset))), "  +--+ RED |");
+
+        let memory = Memory::default();
+        let terminal = set_output(Box::new(memory.clone()));
+        set_plain(true);
+
+        let mut uni = crate::Universe::new(String::from("asdf"), 1u8);
+        uni.damage.srsensors = 0.0;
+        uni.srscan();
+
+        set_plain(false);
+        set_output(terminal);
+
+        let text = memory.text();
+        assert!(text.starts_with(&format!("Short-range scan of quadrant {} {}:\nEnterprise at sector {} {}.\n", uni.qvert+1, uni.qhoriz+1, uni.sloc/10+1, uni.sloc%10+1)));
+        assert!(text.contains("\nStardate:"));
+        assert!(!text.contains('┃'));
+    }
+
+    #[test]
+    fn test_speed () {
+        use crate::output::{speed, Speed};
+
+        assert_eq!(speed(), Speed::Instant);  // Tests mustn't sleep
+        assert_eq!(Speed::from_name("fast"), Some(Speed::Fast));
+        assert_eq!(Speed::Classic.name(), "classic");
+        assert_eq!(parse_args(String::from("speed f")), CommandType::Speed(String::from("fast")));
+        assert_eq!(parse_args(String::from("speed")), CommandType::Speed(String::new()));
+        assert_eq!(parse_args(String::from("speed slow")), CommandType::Error);
+
+        let started = std::time::Instant::now();
+        crate::io::wait(3);
+        crate::io::slow_prout("Self-destruct in 10 seconds.", crate::io::EXTRA_SLOW, true);
+        assert!(started.elapsed().as_millis() < 100);
+    }
+
+    #[test]
+    fn test_speed_piped () {
+        use crate::output::{pause, set_speed, Speed};
+        use std::io::BufRead;
+
+        // Put a script on stdin, as if the game had been piped into
+        let mut fds = [0; 2];
+        let saved = unsafe {
+            assert_eq!(libc::pipe(fds.as_mut_ptr()), 0);
+            libc::dup(libc::STDIN_FILENO)
+        };
+        let script = b"srscan\n";
+        unsafe {
+            libc::write(fds[1], script.as_ptr() as *const libc::c_void, script.len());
+            libc::close(fds[1]);
+            libc::dup2(fds[0], libc::STDIN_FILENO);
+        }
+
+        set_speed(Speed::Classic);
+        let started = std::time::Instant::now();
+        pause(2000);
+        crate::io::slow_prout("Self-destruct in 10 seconds.", crate::io::EXTRA_SLOW, true);
+        let elapsed = started.elapsed();
+        set_speed(Speed::Instant);
+
+        let mut line = String::new();
+        std::io::stdin().lock().read_line(&mut line).unwrap();
+        unsafe {
+            libc::dup2(saved, libc::STDIN_FILENO);
+            libc::close(saved);
+            libc::close(fds[0]);
+        }
+        assert!(elapsed.as_millis() < 100);
+        assert_eq!(line, "srscan\n");  // The script wasn't taken for a keypress
+    }
+
+    #[test]
+    fn test_wrap () {
+        use crate::output::{char_width, display_width, wrap};
+
+        assert_eq!(wrap("Torpedo misses.", 80), "Torpedo misses.");
+        assert_eq!(wrap("one two three four", 9), "one two\nthree\nfour");
+        assert_eq!(wrap("first line\nsecond line", 11), "first line\nsecond line");
+        assert_eq!(wrap("\n ***Torpedo hits", 80), "\n ***Torpedo hits");
+        assert_eq!(wrap("unbreakablewordthatistoolong fits", 10), "unbreakablewordthatistoolong\nfits");
+
+        assert_eq!(
+            wrap("[*Mr. Spock*] Captain, the odds of surviving this are approximately 3720 to 1.", 40),
+            "[*Mr. Spock*] Captain, the odds of\n              surviving this are\n              approximately 3720 to 1."
+        );
+        assert_eq!(wrap("[*A very long-winded speaker*] Hello there.", 20), "[*A very long-winded\nspeaker*] Hello\nthere.");
+
+        assert_eq!(char_width('a'), 1);
+        assert_eq!(char_width('星'), 2);
+        assert_eq!(char_width('\u{0301}'), 0);
+        assert_eq!(display_width("星図"), 4);
+        assert_eq!(wrap("星図 星図 星図", 10), "星図 星図\n星図");
+    }
+
+    #[test]
+    fn test_transcript () {
+        use crate::output::{capture, emit, set_output, Kind, Memory, Message};
+        use crate::transcript::{record_input, set_stardate, start, stop};
+
+        let path = std::env::temp_dir().join(format!("sst_transcript_{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let terminal = set_output(Box::new(Memory::default()));
+
+        start(&path).unwrap();
+        emit(Message::new("Password: ").kind(Kind::Prompt).no_newline());
+        record_input("Password: ", "hunter2");
+        set_stardate(2500.0);
+        emit(Message::new("\nTrack for torpedo #1: (5, 5)").no_newline());
+        emit(Message::new(", (5, 6)").no_newline());
+        emit(Message::new("\nTorpedo misses."));
+        emit(Message::new("\nCommad > ").kind(Kind::Prompt).no_newline());
+        record_input("\nCommad > ", "srscan");
+        assert_eq!(capture(|| emit(Message::new("Full-screen panel"))), vec!["Full-screen panel"]);
+        let mut uni = crate::Universe::with_seed(String::new(), 1, 0);
+        uni.stardate = 2500.0;
+        uni.add_time(0.5);  // Partway through a command
+        emit(Message::new("Rested."));
+        assert!(stop());
+        assert!(!stop());
+
+        set_output(terminal);
+        let transcript = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(transcript, "# Super Star Trek transcript
+         ? Password: 
+         > ********
+ 2500.00   
+ 2500.00   Track for torpedo #1: (5, 5), (5, 6)
+ 2500.00   Torpedo misses.
+ 2500.00 ? Commad > 
+ 2500.00 > srscan
+ 2500.50   Rested.
+");
+    }
+
+    #[test]
+    fn test_replay () {
+        use crate::output::{set_output, Memory};
+        use crate::replay::{load, next_input, record, replaying, start_recording, start_replay, Entry};
+
+        let path = std::env::temp_dir().join(format!("sst_replay_{}.txt", std::process::id()));
+        let mut uni = crate::Universe::with_seed(String::from("hunter2"), 3, 1701);
+        uni.action_times.phaser = 0.25;
+        start_recording(&path, &uni).unwrap();
+        record(Entry::Command(String::from("phasers")));
+        record(Entry::Answer(String::from("automatic")));
+        record(Entry::Answer(String::new()));
+        record(Entry::Command(String::from("destruct")));
+        record(Entry::Secret);
+        record(Entry::Command(String::from("torpedo 1 5 5")));
+        record(Entry::Panic(String::from("attempt to subtract\nwith overflow")));
+
+        let recording = std::fs::read_to_string(&path).unwrap();
+        let (header, entries) = load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(!recording.contains("hunter2"));
+        assert!(crate::io::is_secret("\nPassword: "));
+        assert_eq!((header.seed, header.difficulty), (1701, 3));
+        assert!(header.check_password("hunter2"));
+        assert!(!header.check_password("hunter3"));
+        assert_eq!(header.universe(String::from("hunter2")).rng, uni.rng);
+        assert_eq!(header.time, 14.0);
+        assert_eq!(header.universe(String::from("hunter2")).action_times, uni.action_times);
+        assert_eq!(entries.len(), 7);
+        assert_eq!(entries[2], Entry::Answer(String::new()));
+        assert_eq!(entries[4], Entry::Secret);
+        assert_eq!(entries[6], Entry::Panic(String::from("attempt to subtract with overflow")));
+
+        // It stops before the command that panicked
+        let memory = Memory::default();
+        let terminal = set_output(Box::new(memory.clone()));
+        start_replay(entries, false);
+        assert_eq!(next_input(true).as_deref(), Some("phasers"));
+        assert_eq!(next_input(false).as_deref(), Some("automatic"));
+        assert_eq!(next_input(false).as_deref(), Some(""));
+        assert_eq!(next_input(true).as_deref(), Some("destruct"));
+        assert_eq!(next_input(false), None);  // The player types the password again
+        assert!(replaying());
+        assert_eq!(next_input(true), None);
+        assert!(!replaying());
+        assert!(memory.text().contains("torpedo 1 5 5"));
+        set_output(terminal);
+
+        std::fs::write(&path, "seed 1\ndifficulty 2\nverifier 00 00\ntime 7\n> srscan\nwhat\n").unwrap();
+        let error = load(&path).unwrap_err();
+        assert!(error.contains('6'));
+        std::fs::write(&path, "seed 1\ndifficulty 2\nverifier 00 00\ntime 7\nactions 0.05 0.1 0.3\n> srscan\nwhat\n").unwrap();
+        let error = load(&path).unwrap_err();
+        assert!(error.contains('7'));
+
+        // Recordings from before the action times could be changed use the defaults
+        std::fs::write(&path, "seed 1\ndifficulty 2\nverifier 00 00\ntime 7\n> srscan\n").unwrap();
+        assert_eq!(load(&path).unwrap().0.action_times, crate::structs::ActionTimes::default());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_args () {
+        use crate::args::Args;
+
+        let parse = |args: &str| Args::parse(args.split_whitespace().map(String::from));
+        assert_eq!(parse("").unwrap(), Args::default());
+
+        let args = parse("-d hard --seed=1701 -p hunter2 --length long --no-color --no-animation --ui full").unwrap();
+        assert_eq!(args, Args {
+            difficulty: Some(3),
+            seed: Some(1701),
+            password: Some(String::from("hunter2")),
+            length: Some(4),
+            no_color: true,
+            no_animation: true,
+            ui: Some(String::from("full")),
+            ..Args::default()
+        });
+        assert!(args.new_game());
+
+        let args = parse("--load game.sst --difficulty=2").unwrap();
+        assert_eq!((args.load.as_deref(), args.difficulty), (Some("game.sst"), Some(2)));
+        assert!(!parse("-p secret").unwrap().new_game());
+
+        let args = parse("--phaser-time 0.5 --deathray-time=0").unwrap();
+        assert!(args.new_game());
+        assert_eq!(args.action_times(), crate::structs::ActionTimes { torpedo: crate::constants::TORPEDO_TIME, phaser: 0.5, deathray: 0.0 });
+        assert!(parse("--torpedo-time -1").is_err());
+        assert!(parse("--phaser-time slow").is_err());
+
+        assert!(parse("--difficulty 5").is_err());
+        assert!(parse("--seed many").is_err());
+        assert!(parse("--ui curses").is_err());
+        assert!(parse("--load").is_err());
+        assert!(parse("--warp 9").is_err());
+
+        let mut uni = crate::Universe::with_seed(String::new(), 1, 0);
+        assert_eq!(uni.time_remaining, 14.0);
+        uni.set_length(1);
+        assert_eq!(uni.time_remaining, 7.0);
+    }
+
+    #[test]
+    fn test_save_versions () {
+        use crate::save::{decode, encode, SaveError, MAGIC, VERSION};
+
+        let uni = crate::Universe::with_seed(String::from("hunter2"), 2, 1701);
+        let mut saved = encode(&uni);
+        assert!(saved.starts_with(&format!("{} {}\n", MAGIC, VERSION)));
+        let loaded = decode(&saved, "hunter2").unwrap();
+        assert_eq!((loaded.seed, loaded.rng), (1701, uni.rng.clone()));
+        assert_eq!(decode(&saved, "hunter3").unwrap_err(), SaveError::WrongPassword);
+
+        // Nothing readable is left in the file, and it can't be changed without the password
+        assert!(!saved.contains("hunter2") && !saved.contains("klingons"));
+        let last = saved.pop().unwrap();
+        saved.push(if last == '0' { '1' } else { '0' });
+        assert_eq!(decode(&saved, "hunter2").unwrap_err(), SaveError::Corrupted);
+
+        // A version 1 save, with the password in the open
+        let mut old = serde_json::to_value(&uni).unwrap();
+        old.as_object_mut().unwrap().remove("action_times");
+        let loaded = decode(&format!("{} 1\nhunter2\0{}", MAGIC, old), "hunter2").unwrap();
+        assert_eq!(loaded.password, "hunter2");
+        assert_eq!(loaded.action_times, crate::structs::ActionTimes::default());
+
+        // A save from before there was a header or a seed
+        let mut old = serde_json::to_value(&uni).unwrap();
+        old.as_object_mut().unwrap().remove("seed");
+        old.as_object_mut().unwrap().remove("rng");
+        let loaded = decode(&format!("hunter2\0{}", old), "hunter2").unwrap();
+        assert_eq!((loaded.seed, loaded.sloc, loaded.stardate), (0, uni.sloc, uni.stardate));
+
+        let newer = encode(&uni).replacen(&format!("{} {}", MAGIC, VERSION), &format!("{} {}", MAGIC, VERSION + 1), 1);
+        assert_eq!(decode(&newer, "hunter2").unwrap_err(), SaveError::TooNew(VERSION + 1));
+        assert_eq!(decode(&format!("{} 1\nhunter2\0{{", MAGIC), "hunter2").unwrap_err(), SaveError::Corrupted);
+        assert_eq!(decode(&format!("{} 2\nsalt 00\n", MAGIC), "hunter2").unwrap_err(), SaveError::Corrupted);
+        assert_eq!(decode("", "").unwrap_err(), SaveError::Corrupted);
+    }
+
+    #[test]
+    fn test_screen () {
+        use crate::output::display_width;
+        use crate::screen::clip;
+        use termion::color::{Fg, Red, Reset};
+
+        let coloured = format!("{}K{} . .", Fg(Red), Fg(Reset));
+        assert_eq!(display_width(&coloured), 5);
+        assert_eq!(clip(&coloured, 10), coloured);
+        assert_eq!(clip(&coloured, 2), format!("{}K{} {}", Fg(Red), Fg(Reset), Fg(Reset)));
+        assert_eq!(clip("Stardate: 2500.00", 8), "Stardate");
+    }
+
+    #[test]
+    fn test_help () {
+        use crate::commands::find;
+        use crate::help::suggest;
+
+        assert_eq!(find("m").unwrap().name, "move");
+        assert_eq!(find("mi").unwrap().name, "mine");
+        assert_eq!(find("sta").unwrap().name, "status");
+        assert_eq!(find("star").unwrap().name, "starchart");
+        assert_eq!(find("pho").unwrap().name, "torpedoes");
+        assert!(find("dea").is_none());
+
+        assert_eq!(find("shields").unwrap().syntax(), "shields [up|down|set] [energy]");
+        assert_eq!(find("probe").unwrap().syntax(), "probe [armed] <automatic|manual> [coordinates ...]");
+
+        assert_eq!(suggest("phasrs"), Some("phasers"));
+        assert_eq!(suggest("torpedos"), Some("torpedoes"));
+        assert_eq!(suggest("xyzzy"), None);
+
+        // The labels come from the message catalog
+        let memory = crate::output::Memory::default();
+        let terminal = crate::output::set_output(Box::new(memory.clone()));
+        crate::help::help(String::from("shields"));
+        crate::output::set_output(terminal);
+        let lines = memory.take();
+        assert!(lines.contains(&crate::msg!("help.syntax", "shields [up|down|set] [energy]")));
+        assert!(lines.contains(&crate::msg!("help.examples")));
+    }
+
+    #[test]
+    fn test_command_registry () {
+        use crate::commands::{find, COMMANDS};
+
+        for command in COMMANDS {
+            // Every name, alias and accepted abbreviation leads back to the same command
+            assert_eq!(find(command.name).unwrap().name, command.name);
+            assert_eq!(find(command.least).unwrap().name, command.name);
+            for (alias, least) in command.aliases {
+                assert_eq!(find(alias).unwrap().name, command.name);
+                assert_eq!(find(least).unwrap().name, command.name);
+            }
+
+            // Every example parses
+            for example in command.examples {
+                assert_ne!(parse_args(String::from(*example)), CommandType::Error, "{}", example);
+            }
+        }
+
+        // The `commands` listing comes straight from the registry, so keep it in order
+        assert!(COMMANDS.windows(2).all(|pair| pair[0].name < pair[1].name));
+
+        // Missing and malformed arguments are errors, not panics
+        assert_eq!(parse_args(String::from("probe")), CommandType::Error);
+        assert_eq!(parse_args(String::from("transporter y")), CommandType::Transporter(1));
+        assert_eq!(parse_args(String::from("move north")), CommandType::Error);
+        assert_eq!(parse_args(String::from("srscan now")), CommandType::Error);
+        assert_eq!(parse_args(String::from("   ")), CommandType::Error);
+        assert_eq!(parse_args(String::from("t 2 1 7")), CommandType::Torpedo(Some(2), vec![1, 7]));
+        assert_eq!(parse_args(String::from("s set 400")), CommandType::Shields(String::from("s"), 400.0));
     }
 
     #[test]
@@ -0,0 +1,92 @@
+//! Message catalogs.
+//!
+//! Crew dialogue isn't written into the game logic directly. Instead every line has an
+//! ID, and is looked up in the catalog for the current locale using `msg!` (which
+//! returns the text) or `say!` (which prints it). Placeholders of the form `{0}`, `{1}`
+//! etc. are filled in with the macros' other arguments.
+//!
+//! Anything missing from a catalog falls back to English, so a translation can be
+//! shipped before it's finished.
+
+use std::cell::Cell;
+
+mod en;
+mod terse;
+
+
+/// A set of translations for every message.
+pub struct Catalog {
+    pub locale: &'static str,
+    pub description: &'static str,
+    pub messages: &'static [(&'static str, &'static str)],
+}
+
+impl Catalog {
+    pub fn get (&self, id: &str) -> Option<&'static str> {
+        self.messages.iter().find(|m| m.0 == id).map(|m| m.1)
+    }
+}
+
+
+/// Every catalog. English comes first, since it's the fallback.
+pub const CATALOGS: &[Catalog] = &[en::CATALOG, terse::CATALOG];
+
+thread_local! {
+    /// The index of the current catalog in `CATALOGS`. Like the rest of the output settings, it's per thread, so tests can't trip each other up.
+    static CURRENT: Cell<usize> = Cell::new(0);
+}
+
+
+/// Switch to a different catalog.
+///
+/// Returns `false` (and leaves the locale alone) if there's no catalog for that locale.
+pub fn set_locale (locale: &str) -> bool {
+    match CATALOGS.iter().position(|c| c.locale == locale.to_lowercase()) {
+        Some(i) => {
+            CURRENT.with(|c| c.set(i));
+            true
+        },
+        None => false
+    }
+}
+
+
+/// Look up a message in the current catalog.
+///
+/// If no catalog has it, the ID itself is returned so that the gap is obvious.
+pub fn get (id: &'static str) -> &'static str {
+    CATALOGS[CURRENT.with(|c| c.get())].get(id)
+        .or_else(|| CATALOGS[0].get(id))
+        .unwrap_or(id)
+}
+
+
+/// Fill in a message's placeholders.
+pub fn fill (text: &str, args: &[String]) -> String {
+    let mut filled = String::from(text);
+    for (i, arg) in args.iter().enumerate() {
+        filled = filled.replace(&format!("{{{}}}", i), arg);
+    }
+    filled
+}
+
+
+/// Get a message from the current catalog, with its placeholders filled in.
+#[macro_export]
+macro_rules! msg {
+    ($id:expr) => ($crate::messages::fill($crate::messages::get($id), &[]));
+    ($id:expr, $($arg:expr),+ $(,)?) => ($crate::messages::fill($crate::messages::get($id), &[$(format!("{}", $arg)),+]));
+}
+
+/// Print a message from the current catalog.
+///
+/// Nothing is printed if the catalog leaves the message blank.
+#[macro_export]
+macro_rules! say {
+    ($($arg:tt)*) => ({
+        let message = $crate::msg!($($arg)*);
+        if !message.is_empty() {
+            $crate::prout!("{}", message);
+        }
+    });
+}
@@ -0,0 +1,273 @@
+//! The English message catalog.
+
+use super::Catalog;
+
+
+pub const CATALOG: Catalog = Catalog {
+    locale: "en",
+    description: "English",
+    messages: &[
+        ("parse.no_args", "[*Mr. Spock*] Captain, that command does not take any arguments."),
+        ("parse.cloak.too_many", "[*Engineering*] Uh... sir, have you been taking your pills lately?"),
+        ("parse.computer.coordinates", "[*Computer*] INVALID COORDINATES."),
+        ("parse.deathray.abbrev", "Due to its awesome power (and tendency to explode in your face), the \"deathray\" command cannot be abbreviated."),
+        ("parse.debug.too_many", "ERROR: Improper usage."),
+        ("parse.debug.unavailable", "I'm sorry, but this command is only available in DEBUG builds."),
+        ("parse.destruct.abbrev", "[*COMPUTER*] I'm sorry, but to prevent accidents Starfleet doesn't allow this command to be abbreviated."),
+        ("parse.freeze.too_many", "Huh?"),
+        ("parse.help.too_many", "Hold yer horses! I can only give you help on one thing at a time."),
+        ("parse.impulse.angle", "[*Helm*] Sir, \"second to the right, turn left after the sun and then straight on till morning\" isn't a valid course."),
+        ("parse.distance", "[*Helm*] That isn't a distance."),
+        ("parse.impulse.too_many", "[*Helm*] Sir, please say that again more slowly."),
+        ("parse.move.angle", "[*Helm*] Sir, \"second to the right, turn left after the sun and then straight on till morning\" isn't a valid direction."),
+        ("parse.move.too_many", "[*Helm*] Sir, can you please say that again more slowly?"),
+        ("parse.phasers.energy", "[*Fire Control*] Captain, that's, like, not a number, bro."),
+        ("parse.probe.mode", "[*Shuttle Bay*] Huh?"),
+        ("parse.probe.coordinates", "[*Shuttle Bay*] Those aren't valid destination coordinates."),
+        ("parse.request.too_many", "[*Mr. Spock*] Captain, I can only report one thing at a time."),
+        ("parse.rest.stardates", "[*Mr. Spock*] Sir, that isn't a number."),
+        ("parse.rest.too_many", "[*Mr. Spock*] Sir, that is illogical."),
+        ("parse.shields.mode", "[*Tactical*] Say again, sir?"),
+        ("parse.shields.energy", "[*Tactical*] Sir, I, like, can't make out what you're saying."),
+        ("parse.shields.too_many", "[*Tactical*] What was that, sir?"),
+        ("parse.thaw.too_many", "Invalid arguments."),
+        ("parse.torpedoes.number", "[*Armory*] Huh?"),
+        ("parse.torpedoes.directions", "[*Armory*] Sir, that firing solution makes no sense!"),
+        ("parse.transporter.yes", "[*Transporter Room*] I didn't quite catch that."),
+        ("parse.transporter.too_many", "[*Transporter Room*] Um... would you mind saying that again sir?"),
+        ("parse.log.too_many", "[*Computer*] ONE FILE AT A TIME, PLEASE."),
+        ("parse.speed.speed", "[*Mr. Sulu*] Sir, the speed must be instant, fast or classic."),
+        ("parse.speed.too_many", "[*Mr. Sulu*] Just the one speed, sir."),
+        ("parse.unalias.name", "[*Mr. Spock*] Captain, which alias should I forget?"),
+        ("parse.unalias.too_many", "[*Mr. Spock*] Captain, I can only forget one alias at a time."),
+        ("parse.warp.factor", "[*Helm*] Sir, that isn't a valid warp factor."),
+        ("parse.warp.too_many", "[*Helm*] Say again, sir?"),
+        ("parse.phasers.plan", "[*Fire Control*] Dude, I need, like, a row, a column and an amount of energy for every target."),
+        ("parse.phasers.mode", "[*Fire Control*] Pull the other one; its got bells on."),
+        ("parse.phasers.too_many", "[*Fire Control*] Captain, you're, like, rambling. When trying to, like, fire in automatic fire control it would be, like, totally radical if you could just, like, give me the amount of energy to fire."),
+        ("parse.invalid.suggest", "[*Mr. Spock*] Captain, that is not a valid command. Did you mean \"{0}\"?"),
+        ("parse.invalid", "[*Mr. Spock*] Captain, that is not a valid command."),
+
+        ("weapons.torpedoes.too_many", "[*Armory*] What do you think we are, the Bank of Ferenginar?"),
+        ("weapons.torpedoes.cloaked", "[*Armory*] Sir, we can't fire torpedoes while the cloaking device is active."),
+        ("weapons.torpedoes.how_many", "How many torpedoes would you like to fire? "),
+        ("weapons.torpedoes.invalid_number", "Sir, I can't fire \"{0}\" torpedoes."),
+        ("weapons.torpedoes.not_enough", "[*Armory*] We can't fire that many sir; we only {0} left."),
+        ("weapons.torpedoes.three_at_a_time", "[*Armory*] Sir, we can only fire three at a time; any more and we would melt the tubes!"),
+        ("weapons.torpedoes.direction", "[*Armory*] Sir, that doesn't make sense."),
+        ("weapons.phasers.damaged", "[*Tactical*] Sorry bro, but the phasers are, like, damaged. I can't, like, fire them until they're repaired."),
+        ("weapons.phasers.cloaked", "[*Tactical*] Dude, we can't, like, fire the phasers while we're cloaked."),
+        ("weapons.phasers.mode", "Automatic or Manual targeting? "),
+        ("weapons.phasers.manual_only", "[*Tactical*] Captain, the compewter's, like, damaged; I can only give you, like, manual targetting.\n"),
+        ("weapons.phasers.nonsense", "[*Tactical*] Sir, that, like, doesn't make any sense."),
+        ("weapons.phasers.energy", "Energy to fire: "),
+        ("weapons.phasers.outside", "[*Tactical*] Sector {0} {1} is, like, totally outside the quadrant, dude."),
+        ("weapons.phasers.no_target", "[*Tactical*] Captain, there's, like, nothing to shoot at in sector {0} {1}."),
+        ("weapons.phasers.duplicate", "[*Tactical*] Bro, you already, like, told me to shoot at sector {0} {1}."),
+        ("weapons.phasers.target_energy", "How much energy to fire? (leave blank to skip) "),
+        ("weapons.phasers.nice", "Nice!"),
+        ("weapons.phasers.no_targets", "[*Tactical*] No targets, no boom, bro."),
+        ("weapons.phasers.plan", "\n[*Tactical*] Firing plan, like, locked in:"),
+        ("weapons.phasers.overheated", "\n[*Tactical*] Rad! ...uh, captain, the phasers have, like, overheated."),
+        ("weapons.phasers.negative", "[*Tactical*] Captain, we, like, can't fire negative amounts of, like, energy."),
+        ("weapons.phasers.shield_control", "[*Engineering*] Captain, we donna ha' enough power ta fire the phasers wi' high-speed shield control."),
+        ("weapons.phasers.lower_shields", "[*Engineering*] ...that said, we *could* do it if we manually lower the shields."),
+        ("weapons.phasers.no_power", "[*Engineering*] Captain, we donna ha' the power to do that."),
+
+        ("input.shouting", "Please don't shout; it hurts the crew's feelings."),
+
+        ("defense.cloak.damaged", "[*Engineering*] Sir, we canna' control the cloaking device until this damage is repaired."),
+        ("defense.cloak.treaty", "[*Mr. Spock*] Captain, using the cloaking device would violate the Treaty of Algeron. If the Romulans catch us using it, it will bring their ire upon us. Are you sure you want to use it?\n> "),
+        ("defense.cloak.no_power", "[*Engineering*] We donna' have the power to keep the cloak up, captain."),
+        ("defense.cloak.shields_down", "[*Tactical*] Shields, like, going down for the cloak."),
+        ("defense.cloak.drained", "\n[*Engineering*] Captain, the cloak's drainin' us dry! I'm shuttin' it down."),
+        ("defense.shields.damaged", "[*Tactical*] Sir, the shield control circuit thingies are broke; I can't do anything till they're repaired."),
+        ("defense.shields.cloaked", "[*Tactical*] Sir, the shields, like, can't be used while we're cloaked."),
+        ("defense.shields.mode", "[*Tactical*] Waddya wanna do: put 'em up, take 'em down, or set the energy?\n> "),
+        ("defense.shields.set_to", "[*Tactical*] Whaddya wanna have me set 'em to?\n> "),
+        ("defense.shields.nonsense", "[*Tactical*] I, like, can't understand what ya sayin'."),
+        ("defense.shields.too_little", "[*Tactical*] I can't return more energy than actually's in the capacitors."),
+        ("defense.shields.too_much", "[*Tactical*] The shields, like, can't hold more than 600 energy."),
+        ("defense.shields.no_power", "[*Engineering*] Captain, we don't have that much energy available."),
+        ("defense.shields.come_again", "[*Tactical*] Come again?"),
+
+        ("movement.impulse.damaged", "[*Engineering*] Sir, the Impulse Drive is inoperable. We canna' use it."),
+        ("movement.warp.smashed", "[*Engineering*] The warp coils are smashed! Using it right now would blow the ship to smithereens!"),
+        ("movement.warp.damaged", "[*Engineering*] The warp engines are damaged, sir; I can only give you warp 2.5."),
+        ("movement.warp.cloaked", "[*Engineering*] We canna' use the warp drive while the claoking device is active!"),
+        ("movement.warp.try_impulse", "[*Engineering*] ... that said, I could probably give you impulse."),
+        ("movement.direction", "Direction: "),
+        ("movement.angle", "[*Helm*] That isn't an angle."),
+        ("movement.distance", "Distance: "),
+        ("movement.invalid_distance", "[*Helm*] \"Second to the right and straight on till morning\" isn't a valid course."),
+        ("movement.too_short", "[*Helm*] Sir, we can't move that short of a distance."),
+        ("movement.no_power", "[*Mr. Spock*] Captain, we do not have sufficient power to complete that manuever."),
+        ("movement.no_power.range", "According to my calculations, we can only go {0} quadrants before we run out of power."),
+        ("movement.no_power.warp", "That said, we could do it at warp {0}."),
+        ("movement.no_power.warp_shields", "That said, we could do it at warp {0}, provided we lower the shields."),
+        ("movement.impulse.slow", "[*Mr. Spock*] Captain, we can only go 0.95 sectors per stardate under impulse power. Are you sure we dare spend the time?"),
+        ("movement.warp.slow", "[*Mr. Spock*] Sir, that would take us {0}% of our remaining time. Are you sure this is wise?"),
+        ("movement.romulan.hail", "\n[*Lt. Uhura*] Captain, a Romulan ship is hailing us. I'll put it on audio."),
+        ("movement.warp.factor", "[*Helm*] Say again, sir?"),
+        ("movement.warp.prompt", "New warp factor: "),
+        ("movement.warp.physics", "[*Engineering*] Do you think I'm God? I canna' change the laws of physics!\n[*Mr. Spock*] Captain, we can only go up to warp 10."),
+        ("movement.warp.risky", "[*Mr. Spock*] Sir, we'd risk damaging the warp engines at that speed. Are you sure the risk is worth it?\n> "),
+        ("movement.rest.prompt", "\nHow much time would you like to skip? "),
+        ("movement.rest.nonsense", "Huh?"),
+        ("movement.rest.negative", "[*Mr. Spock*] Captain, need I remind you that under normal conditions we always go forwards in time?"),
+        ("movement.rest.too_long", "Captain, that would take more than our remaining time. Are you sure you wish to do this?\n> "),
+        ("movement.call.radio", "[*Lt. Uhura*] Captain, the subspace radio is inoperable."),
+        ("movement.call.no_answer", "[*Lt. Uhura*] I'm sorry captain... nobody's responding to our distress calls."),
+        ("movement.call.confirm", "[*Lt. Sulu*] Captain, are you sure this is a good idea?\n> "),
+        ("movement.call.full", "[*Lt. Uhura*] Captain, a starbase received our distress call. The base commandant sends his condolences; he doesn't have any open births."),
+        ("movement.supernova.detected", "[*Comp.*] SUPERNOVA DETECTED"),
+        ("movement.supernova.override", "[*Comp.*] ENGAGING EMERGENCY ENGINE OVERRIDE"),
+        ("movement.supernova.interlinks", "[*Comp.*] !ERROR!: CONTROL INTERLINKS INOPERABLE!"),
+        ("movement.supernova.failed", "[*Comp.*] OVERRIDE FAILED."),
+        ("movement.supernova.fish", " SO LONG, AND THANKS FOR ALL THE FISH."),
+
+        ("alias.save_failed", "[*Computer*] UNABLE TO SAVE ALIASES."),
+        ("alias.none", "No aliases have been defined. Try `alias fire = phasers auto 500`."),
+        ("alias.defined", "[*Mr. Spock*] Noted, Captain."),
+        ("alias.unknown", "[*Mr. Spock*] Captain, there is no alias called \"{0}\"."),
+        ("alias.form", "[*Mr. Spock*] Captain, an alias must be of the form `name = commands`."),
+        ("alias.bad_name", "[*Mr. Spock*] Captain, \"{0}\" is not a valid name for an alias."),
+        ("alias.taken", "[*Mr. Spock*] Captain, \"{0}\" already means \"{1}\"."),
+        ("alias.empty", "[*Mr. Spock*] Captain, an alias must stand for at least one command."),
+        ("alias.recursive", "[*Mr. Spock*] Captain, the alias \"{0}\" appears to refer to itself."),
+        ("alias.arguments", "[*Mr. Spock*] Captain, \"{0}\" needs {1} arguments."),
+        ("alias.file_error", "[*Computer*] ERROR IN THE ALIAS FILE: {0}"),
+
+        ("speed.current", "[*Computer*] TEXT SPEED: {0}."),
+        ("speed.unknown", "[*Computer*] UNKNOWN TEXT SPEED: {0}."),
+
+        ("transcript.started", "[*Computer*] RECORDING SESSION TO {0}."),
+        ("transcript.stopped", "[*Computer*] RECORDING STOPPED."),
+        ("transcript.status", "[*Computer*] SESSION IS BEING RECORDED TO {0}."),
+        ("transcript.none", "[*Computer*] SESSION IS NOT BEING RECORDED."),
+        ("transcript.failed", "[*Computer*] UNABLE TO RECORD TO {0}."),
+
+        ("replay.recording", "[*Computer*] LOGGING ALL COMMANDS TO {0} FOR REPLAY."),
+        ("replay.record_failed", "[*Computer*] UNABLE TO LOG COMMANDS TO {0}."),
+        ("replay.load_failed", "[*Computer*] UNABLE TO REPLAY {0}: {1}"),
+        ("replay.missing", "MISSING OR INVALID {0}"),
+        ("replay.bad_line", "LINE {0} IS NOT A COMMAND, ANSWER OR PANIC: {1}"),
+        ("replay.wrong_password", "[*Computer*] PASSWORD DOES NOT MATCH THE RECORDING. REPLAY DENIED."),
+        ("replay.started", "[*Computer*] REPLAYING {0}. SEED {1}, DIFFICULTY {2}."),
+        ("replay.step", "[*Computer*] NEXT COMMAND: {0}. ENTER TO PROCEED, Q TO TAKE COMMAND. "),
+        ("replay.stopped", "[*Computer*] REPLAY HALTED. YOU HAVE THE CONN, CAPTAIN."),
+        ("replay.finished", "[*Computer*] END OF RECORDING. YOU HAVE THE CONN, CAPTAIN."),
+        ("replay.panicked", "[*Computer*] REPLAY HALTED. THE RECORDED GAME FAILED ON THE NEXT INPUT, \"{0}\": {1}"),
+        ("replay.panicked.start", "[*Computer*] REPLAY HALTED. THE RECORDED GAME FAILED BEFORE ANY INPUT: {0}"),
+
+        ("queue.cancelled.error", "[*Mr. Spock*] Captain, I have cancelled the rest of your orders until that is cleared up."),
+        ("queue.cancelled.interrupted", "\n[*Mr. Spock*] Captain, we have been interrupted. I have cancelled the rest of your orders."),
+
+        ("events.starbase_attack", "\n[*Lt. UHURA*] Captain, we just received a distress call from the starbase in quadrant {0} {1}. The base is under attack by Klingons, and can only hold out until stardate {2}."),
+        ("events.starbase_destroyed", "[*Lt. UHURA*] Sir, an APB just came in from Starfleet. The Klingons have destroyed the starbase in quadrant {0} {1}. I'm sorry sir."),
+
+        ("computer.inoperable", "[*Mr. Spock*] Captain, the computer is inoperable. I am afraid you will have to do the math yourself."),
+        ("computer.destination", "[*Computer*] DESTINATION QUADRANT AND SECTOR? "),
+        ("computer.invalid", "[*Computer*] INVALID COORDINATES."),
+        ("computer.specify", "[*Computer*] PLEASE SPECIFY A QUADRANT, AND OPTIONALLY A SECTOR."),
+        ("computer.outside", "[*Computer*] THOSE COORDINATES ARE OUTSIDE THE GALAXY."),
+        ("computer.already_there", "[*Computer*] YOU ARE ALREADY THERE."),
+        ("computer.damaged", "[*Mr. Spock*] Captain, the computer is damaged. I would not place too much faith in these figures."),
+        ("computer.course", "\n[*Computer*] COURSE TO QUADRANT {0} {1}, SECTOR {2} {3}:"),
+        ("computer.direction", "  Direction:  {0}"),
+        ("computer.distance", "  Distance:   {0} sectors"),
+        ("computer.warp", "  Warp {0} (shields {1}): {2} energy, {3} stardates"),
+        ("computer.shields.up", "up"),
+        ("computer.shields.down", "down"),
+        ("computer.impulse", "  Impulse:    {0} energy, {1} stardates"),
+        ("computer.no_energy", "[*Computer*] WARNING: INSUFFICIENT ENERGY FOR EITHER DRIVE."),
+        ("computer.no_time", "[*Computer*] WARNING: THE TRIP WILL TAKE LONGER THAN THE TIME REMAINING."),
+
+        ("help.unknown.suggest", "[*Mr. Spock*] Captain, there is no such command. Did you mean \"{0}\"?"),
+        ("help.unknown", "[*Mr. Spock*] Captain, there is no such command. Type `commands` for a list."),
+        ("help.intro", "Type `help <command>` for help on a specific command. For example: help phasers"),
+        ("help.semicolons", "Several commands can be given on one line by separating them with semicolons. For example: shields up; move 45 3; srscan"),
+        ("help.syntax", "Syntax: {0}"),
+        ("help.abbreviation", "Abbreviation: {0}"),
+        ("help.abbreviation.none", "Abbreviation: none; the full name must be typed."),
+        ("help.aliases", "Also known as: {0}"),
+        ("help.examples", "Examples:"),
+        ("help.commands", "\nAvailable commands:"),
+
+        ("scans.request.unknown", "[*Mr. Spock*] Captain, I can report the following: stardate, time, klingons, starbases, energy, shields, torpedoes, warpfactor, crew, lsupport, condition, position."),
+        ("scans.lrscan.damaged", "[*Mr. Spock*] Sir, the long range sensors are inoperable due to damage."),
+        ("scans.srscan.header", "Short-range scan of quadrant {0} {1}:"),
+        ("scans.srscan.damaged", "Sensors damaged; only the sectors next to the ship can be seen."),
+        ("scans.srscan.enterprise", "Enterprise at sector {0}."),
+        ("scans.srscan.klingon", "Klingon at sector {0}."),
+        ("scans.srscan.klingons", "Klingons at sectors {0}."),
+        ("scans.srscan.romulan", "Romulan at sector {0}."),
+        ("scans.srscan.romulans", "Romulans at sectors {0}."),
+        ("scans.srscan.tholian", "Tholian at sector {0}."),
+        ("scans.srscan.tholians", "Tholians at sectors {0}."),
+        ("scans.srscan.unknown", "Unknown object at sector {0}."),
+        ("scans.srscan.unknowns", "Unknown objects at sectors {0}."),
+        ("scans.srscan.starbase", "Starbase at sector {0}."),
+        ("scans.srscan.starbases", "Starbases at sectors {0}."),
+        ("scans.srscan.black_hole", "Black hole at sector {0}."),
+        ("scans.srscan.black_holes", "Black holes at sectors {0}."),
+        ("scans.srscan.star", "Star at sector {0}."),
+        ("scans.srscan.stars", "Stars at sectors {0}."),
+
+        ("defense.romulan.detected", "\nA Romulan ship has observed you using your cloaking device. From now on, all Romulan ships will be hostile towards you."),
+
+        ("finish.destruct.confirm", "\n[* Mr. Spock *] Captain, are you sure you want to activate the self destruct?\n> "),
+        ("finish.destruct.password", "\nPassword: "),
+        ("finish.destruct.countdown", "[*Computer*] Self-destruct in 10 seconds."),
+        ("finish.destruct.goodbye", "           Goodbye, cruel world!\n"),
+
+        ("deathray.damaged", "[*Tactical*] Sir, the deathray's, like, damaged. I can't, like, fire it in this condition."),
+        ("deathray.cloaked", "[*Mr. Spock*] Captain, the deathray cannot be fired while the cloaking device is active."),
+        ("deathray.no_enemies", "[*Mr. Spock*] Captain, there are no enemies in this quadrant to fire at."),
+        ("deathray.no_power", "[*Engineering*] Sir, we donna' have enough energy to fire that infernal thing."),
+        ("deathray.unrepaired", "[*Mr. Spock*] Captain, the deathray has not been fully repaired. The odds of success are even worse than usual."),
+        ("deathray.confirm", "[*Mr. Spock*] The deathray is still experimental. If we use it there is a large chance that the Enterprise will be destroyed. Are you sure you want to proceed?\n> "),
+        ("deathray.as_you_wish", "[*Mr. Spock*] As you wish.\n"),
+        ("deathray.siren", "WHOOee ... WHOOee ... WHOOee ... WHOOee"),
+        ("deathray.scramble", "The crew scrambles in emergency preparation."),
+        ("deathray.preparations", "Spock and Scotty ready the deathray and prepare to channel all the ship's power to the device.\n"),
+        ("deathray.ready", "[*Mr. Spock*] Preparations are complete, captain."),
+        ("deathray.fire", "[*Cpt. Kirk*] Fire!"),
+        ("deathray.whirr", "WHIRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRRR"),
+        ("deathray.working", "[*Mr. Sulu*] Captain, it's working!"),
+        ("deathray.breach.alert", "***RED ALERT! RED ALERT!***"),
+        ("deathray.breach.imminent", "***WARP CORE BREACH IMMINENT!***"),
+        ("deathray.breach.cut_off", "***RED ALERT! RED A*L********************************"),
+        ("deathray.breach.boom", "*********************** BOOOM ***********************"),
+        ("deathray.breach.stars", "*****************************************************"),
+        ("deathray.mutation.sulu", "[*Mr. Sulu*] Captain, Yagsdsadfagag, brascscha!\n"),
+        ("deathray.mutation.uhura", "[*Lt. Uhura*] Graeeek! Graeeek!\n"),
+        ("deathray.mutation.spock", "[*Mr. Spock*] Fascinating! It would seem that all the humans aboard have been transformed into strange mutations."),
+        ("deathray.mutation.vulcans", "[*Mr. Spock*] Thankfully, Vulcans do not appear to be affected.\n"),
+        ("deathray.mutation.kirk", "[*Cpt. Kirk*] Raauuch!"),
+        ("deathray.borg.readings", "[*Mr. Spock*] Captain, I am getting some illogical sensor readings."),
+        ("deathray.borg.wormhole", "[Cont.] There appears to be a wormhole nearby, but that's impossible; the starcharts do not show any in this area."),
+        ("deathray.borg.cube", "[Cont.] Interesting... there appears to be a massive cube-shaped ship coming through it."),
+        ("deathray.borg.size", "[*Mr. Sulu*] Look at the size of that thing!"),
+        ("deathray.borg.hailing", "[*Lt. Uhura*] Captain, it's hailing us."),
+        ("deathray.borg.message", "*click* We are the Borg. Lower your shields and surrender your ship. Your biological and technological distinctiveness will be added to our own. Your culture will adapt to service us. Resistance is futile."),
+        ("deathray.tribbles", "[*Mr. Sulu*] Um... Captain, it appears to be making tribbles?"),
+        ("deathray.eldritch", "[*Mr. Sulu*] That's weird. It ḋ̵͉͊oesn't app̞ear tǫ be do̷̮͑̍ing anythH̵̤̙̥͇̚Ȩ̷̳̣͖̊ ̶̩̻͍͎̒C̴̖͔͈͗̓̍ͅO̶̢̦̳͓̅̑̈͠M̷̢͠È̴̙͌S̶̢͓̗̃͆́ GAAAAAAH AAAA GAH HELP PLEaD̶̡͍̖͍̫̲̤̔̔̏͒̍̒̋̐̕̚Ǒ̵̡̥͍͖̭̅͒̀̈́́́̆́̑͘̚͠͝ͅ ̶̢̧̦̰̝̬̝̟̰̩̝͎̹͐̋̂̐̇̏̂̐́̈́̐͌̇̾͘͠͠N̸̘̹͖̭̪̺̪͙̟͔̻͎͗̍͋͑͒̇̓̌̓̃̃̂̕͝Ơ̵͖̻̠̜͇͇̻̿̉̽͒̆̆̑̿̿͋̔̇T̷̗̤̭̞͙͚̆̀͋̉ͅ ̸̼̹̦͖̱͔͖̮̩͇̖͎̼̦̯̠̊̈̉͆̓̅̉͝͠ͅR̴̡̼͙͚͚̟̗͇͓̻̳̰͙͇̺̈́̑̽͛̇̊̓͒͘͘͘͝E̵͙̋S̷̨͝Ȉ̸̫Ṡ̶̥T̷̗̈́ GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAH!"),
+        ("deathray.eldritch.plain", "Eldritch abominations are incompatible with this old hunk of junk. Get out of the stone age and buy a computer that supports unicode."),
+        ("deathray.inoperable", "[*Mr. Spock*] Captain, the experimental deathray has been rendered inoperable."),
+
+        ("save.prompt", "Save file: "),
+        ("save.not_found", "ERROR: Unable to find save file.\n"),
+        ("save.password", "Password: "),
+        ("save.error", "\nERROR: {0}"),
+        ("save.corrupted", "The save file is corrupted."),
+        ("save.wrong_password", "That password is incorrect."),
+        ("save.too_new", "The save file is format version {0}, but this game only understands up to version {1}. Please upgrade to load it."),
+        ("save.filename", "Filename: "),
+        ("save.cant_create", "Alas, it is impossible to create a file in that location."),
+        ("save.cant_write", "I'm sorry, but that file cannot be written to."),
+        ("save.created", "Game back-up created in {0}"),
+        ("save.emergency_failed", "ERROR: Unable to save."),
+        ("save.continuing", "Continuing the current game."),
+    ],
+};
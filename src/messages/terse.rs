@@ -0,0 +1,244 @@
+//! The terse English message catalog, without the crew banter.
+
+use super::Catalog;
+
+
+pub const CATALOG: Catalog = Catalog {
+    locale: "terse",
+    description: "terse English, without the banter",
+    messages: &[
+        ("parse.no_args", "That command takes no arguments."),
+        ("parse.cloak.too_many", "Too many arguments to cloak."),
+        ("parse.computer.coordinates", "Invalid coordinates."),
+        ("parse.deathray.abbrev", "\"deathray\" cannot be abbreviated."),
+        ("parse.debug.too_many", "Improper usage."),
+        ("parse.debug.unavailable", "Only available in DEBUG builds."),
+        ("parse.destruct.abbrev", "\"destruct\" cannot be abbreviated."),
+        ("parse.freeze.too_many", "Too many arguments to freeze."),
+        ("parse.help.too_many", "Help is only available on one command at a time."),
+        ("parse.impulse.angle", "Invalid angle."),
+        ("parse.distance", "Invalid distance."),
+        ("parse.impulse.too_many", "Too many arguments to impulse."),
+        ("parse.move.angle", "Invalid angle."),
+        ("parse.move.too_many", "Too many arguments to move."),
+        ("parse.phasers.energy", "Invalid amount of energy."),
+        ("parse.probe.mode", "The probe's mode must be automatic or manual."),
+        ("parse.probe.coordinates", "Invalid coordinates."),
+        ("parse.request.too_many", "Only one item can be requested at a time."),
+        ("parse.rest.stardates", "Invalid number of stardates."),
+        ("parse.rest.too_many", "Too many arguments to rest."),
+        ("parse.shields.mode", "The shields' mode must be up, down or set."),
+        ("parse.shields.energy", "Invalid amount of energy."),
+        ("parse.shields.too_many", "Too many arguments to shields."),
+        ("parse.thaw.too_many", "Too many arguments to thaw."),
+        ("parse.torpedoes.number", "Invalid number of torpedoes."),
+        ("parse.torpedoes.directions", "Invalid torpedo directions."),
+        ("parse.transporter.yes", "Expected yes or no."),
+        ("parse.transporter.too_many", "Too many arguments to transporter."),
+        ("parse.log.too_many", "Only one file can be given."),
+        ("parse.speed.speed", "The speed must be instant, fast or classic."),
+        ("parse.speed.too_many", "Only one speed can be given."),
+        ("parse.unalias.name", "Which alias?"),
+        ("parse.unalias.too_many", "Only one alias can be removed at a time."),
+        ("parse.warp.factor", "Invalid warp factor."),
+        ("parse.warp.too_many", "Too many arguments to warp."),
+        ("parse.phasers.plan", "A firing plan needs a row, a column and an amount of energy for every target."),
+        ("parse.phasers.mode", "The phasers' mode must be automatic, manual, or an amount of energy."),
+        ("parse.phasers.too_many", "Automatic fire control only takes an amount of energy."),
+        ("parse.invalid.suggest", "Invalid command. Did you mean \"{0}\"?"),
+        ("parse.invalid", "Invalid command."),
+
+        ("weapons.torpedoes.too_many", "Not enough torpedoes."),
+        ("weapons.torpedoes.cloaked", "Torpedoes can't be fired while cloaked."),
+        ("weapons.torpedoes.how_many", "Torpedoes to fire: "),
+        ("weapons.torpedoes.invalid_number", "Invalid number of torpedoes: {0}"),
+        ("weapons.torpedoes.not_enough", "Only {0} torpedoes left."),
+        ("weapons.torpedoes.three_at_a_time", "At most three torpedoes can be fired at a time."),
+        ("weapons.torpedoes.direction", "Invalid direction."),
+        ("weapons.phasers.damaged", "The phasers are damaged."),
+        ("weapons.phasers.cloaked", "The phasers can't be fired while cloaked."),
+        ("weapons.phasers.mode", "Automatic or manual: "),
+        ("weapons.phasers.manual_only", "The computer is damaged; manual targeting only.\n"),
+        ("weapons.phasers.nonsense", "Invalid input."),
+        ("weapons.phasers.energy", "Energy to fire: "),
+        ("weapons.phasers.outside", "Sector {0} {1} is outside the quadrant."),
+        ("weapons.phasers.no_target", "Nothing to fire at in sector {0} {1}."),
+        ("weapons.phasers.duplicate", "Sector {0} {1} is already targeted."),
+        ("weapons.phasers.target_energy", "Energy (blank to skip): "),
+        ("weapons.phasers.nice", ""),
+        ("weapons.phasers.no_targets", "No targets."),
+        ("weapons.phasers.plan", "\nFiring plan:"),
+        ("weapons.phasers.overheated", "\nThe phasers have overheated."),
+        ("weapons.phasers.negative", "The energy can't be negative."),
+        ("weapons.phasers.shield_control", "Not enough energy to fire with the shields up."),
+        ("weapons.phasers.lower_shields", "Lower the shields first."),
+        ("weapons.phasers.no_power", "Not enough energy."),
+
+        ("input.shouting", "Please don't shout."),
+
+        ("defense.cloak.damaged", "The cloaking device is damaged."),
+        ("defense.cloak.treaty", "Using the cloak violates the Treaty of Algeron. Continue? "),
+        ("defense.cloak.no_power", "Not enough energy to cloak."),
+        ("defense.cloak.shields_down", "Shields lowered."),
+        ("defense.cloak.drained", "\nCloak disengaged: not enough energy."),
+        ("defense.shields.damaged", "The shields are damaged."),
+        ("defense.shields.cloaked", "The shields can't be used while cloaked."),
+        ("defense.shields.mode", "Up, down or set: "),
+        ("defense.shields.set_to", "Shield energy: "),
+        ("defense.shields.nonsense", "Invalid amount of energy."),
+        ("defense.shields.too_little", "The shields don't hold that much energy."),
+        ("defense.shields.too_much", "The shields can't hold more than 600 energy."),
+        ("defense.shields.no_power", "Not enough energy."),
+        ("defense.shields.come_again", "The shields' mode must be up, down or set."),
+
+        ("movement.impulse.damaged", "The impulse drive is inoperable."),
+        ("movement.warp.smashed", "The warp drive is inoperable."),
+        ("movement.warp.damaged", "The warp drive is damaged; maximum warp 2.5."),
+        ("movement.warp.cloaked", "The warp drive can't be used while cloaked."),
+        ("movement.warp.try_impulse", "Impulse is available."),
+        ("movement.direction", "Direction: "),
+        ("movement.angle", "Invalid angle."),
+        ("movement.distance", "Distance: "),
+        ("movement.invalid_distance", "Invalid distance."),
+        ("movement.too_short", "The distance must be at least 1 sector."),
+        ("movement.no_power", "Not enough energy."),
+        ("movement.no_power.range", "Range: {0} quadrants."),
+        ("movement.no_power.warp", "Possible at warp {0}."),
+        ("movement.no_power.warp_shields", "Possible at warp {0} with the shields down."),
+        ("movement.impulse.slow", "Impulse only goes 0.95 sectors per stardate. Continue?"),
+        ("movement.warp.slow", "That would take {0}% of the remaining time. Continue?"),
+        ("movement.romulan.hail", "\nIncoming Romulan transmission:"),
+        ("movement.warp.factor", "Invalid warp factor."),
+        ("movement.warp.prompt", "Warp factor: "),
+        ("movement.warp.physics", "The warp factor must be above 0 and at most 10."),
+        ("movement.warp.risky", "Above warp 6 the engines may be damaged. Continue? "),
+        ("movement.rest.prompt", "\nStardates to rest: "),
+        ("movement.rest.nonsense", "Invalid number of stardates."),
+        ("movement.rest.negative", "The time can't be negative."),
+        ("movement.rest.too_long", "That is longer than the time remaining. Continue? "),
+        ("movement.call.radio", "The subspace radio is inoperable."),
+        ("movement.call.no_answer", "No response."),
+        ("movement.call.confirm", "Attempt the transport? "),
+        ("movement.call.full", "The starbase has no room for the ship."),
+        ("movement.supernova.detected", "SUPERNOVA DETECTED"),
+        ("movement.supernova.override", "EMERGENCY OVERRIDE ENGAGED"),
+        ("movement.supernova.interlinks", "CONTROL INTERLINKS INOPERABLE"),
+        ("movement.supernova.failed", "OVERRIDE FAILED."),
+        ("movement.supernova.fish", ""),
+
+        ("alias.save_failed", "Unable to save aliases."),
+        ("alias.none", "No aliases defined."),
+        ("alias.defined", "Alias defined."),
+        ("alias.unknown", "No such alias: {0}"),
+        ("alias.form", "An alias must be of the form `name = commands`."),
+        ("alias.bad_name", "Invalid alias name: {0}"),
+        ("alias.taken", "\"{0}\" already means \"{1}\"."),
+        ("alias.empty", "An alias needs at least one command."),
+        ("alias.recursive", "The alias \"{0}\" refers to itself."),
+        ("alias.arguments", "\"{0}\" needs {1} arguments."),
+        ("alias.file_error", "Error in the alias file: {0}"),
+
+        ("speed.current", "Text speed: {0}."),
+        ("speed.unknown", "Unknown text speed: {0}."),
+
+        ("transcript.started", "Recording to {0}."),
+        ("transcript.stopped", "Recording stopped."),
+        ("transcript.status", "Recording to {0}."),
+        ("transcript.none", "Not recording."),
+        ("transcript.failed", "Unable to record to {0}."),
+
+        ("replay.recording", "Logging commands to {0}."),
+        ("replay.record_failed", "Unable to log commands to {0}."),
+        ("replay.load_failed", "Unable to replay {0}: {1}"),
+        ("replay.missing", "missing or invalid {0}"),
+        ("replay.bad_line", "line {0} is not a command, answer or panic: {1}"),
+        ("replay.wrong_password", "Wrong password for that recording."),
+        ("replay.started", "Replaying {0} (seed {1}, difficulty {2})."),
+        ("replay.step", "Next: {0} (enter to go on, q to stop) "),
+        ("replay.stopped", "Replay stopped."),
+        ("replay.finished", "Replay finished."),
+        ("replay.panicked", "Replay stopped; the game panicked on \"{0}\": {1}"),
+        ("replay.panicked.start", "Replay stopped; the game panicked before any input: {0}"),
+
+        ("queue.cancelled.error", "Remaining commands cancelled."),
+        ("queue.cancelled.interrupted", "\nInterrupted; remaining commands cancelled."),
+
+        ("events.starbase_attack", "\nThe starbase in quadrant {0} {1} is under attack, and will fall at stardate {2}."),
+        ("events.starbase_destroyed", "The starbase in quadrant {0} {1} has been destroyed."),
+
+        ("computer.inoperable", "The computer is inoperable."),
+        ("computer.destination", "Destination quadrant and sector? "),
+        ("computer.invalid", "Invalid coordinates."),
+        ("computer.specify", "Give a quadrant, and optionally a sector."),
+        ("computer.outside", "Those coordinates are outside the galaxy."),
+        ("computer.already_there", "You are already there."),
+        ("computer.damaged", "The computer is damaged; these figures may be off."),
+        ("computer.course", "\nCourse to quadrant {0} {1}, sector {2} {3}:"),
+        ("computer.no_energy", "Not enough energy for either drive."),
+        ("computer.no_time", "The trip would take longer than the time remaining."),
+
+        ("help.unknown.suggest", "No such command. Did you mean \"{0}\"?"),
+        ("help.unknown", "No such command. Type `commands` for a list."),
+        ("help.intro", "Type `help <command>` for help on a command, e.g. help phasers"),
+        ("help.semicolons", "Separate commands with semicolons, e.g. shields up; move 45 3; srscan"),
+        ("help.syntax", "Syntax: {0}"),
+        ("help.abbreviation", "Abbreviation: {0}"),
+        ("help.abbreviation.none", "Abbreviation: none"),
+        ("help.aliases", "Also known as: {0}"),
+        ("help.examples", "Examples:"),
+        ("help.commands", "\nCommands:"),
+
+        ("scans.request.unknown", "Options: stardate, time, klingons, starbases, energy, shields, torpedoes, warpfactor, crew, lsupport, condition, position."),
+        ("scans.lrscan.damaged", "Long-range sensors are damaged."),
+
+        ("defense.romulan.detected", "\nThe Romulans saw you using the cloaking device. They are now hostile."),
+
+        ("finish.destruct.confirm", "\nActivate the self-destruct? "),
+        ("finish.destruct.countdown", "Self-destruct in 10 seconds."),
+        ("finish.destruct.goodbye", ""),
+
+        ("deathray.damaged", "The deathray is damaged."),
+        ("deathray.cloaked", "The deathray can't be fired while cloaked."),
+        ("deathray.no_enemies", "There are no enemies in this quadrant."),
+        ("deathray.no_power", "Not enough energy to fire the deathray."),
+        ("deathray.unrepaired", "The deathray isn't fully repaired; the odds are worse."),
+        ("deathray.confirm", "The deathray may destroy the Enterprise. Fire it? "),
+        ("deathray.as_you_wish", ""),
+        ("deathray.siren", ""),
+        ("deathray.scramble", ""),
+        ("deathray.preparations", ""),
+        ("deathray.ready", ""),
+        ("deathray.fire", "Firing the deathray."),
+        ("deathray.whirr", ""),
+        ("deathray.working", "The deathray is working."),
+        ("deathray.breach.alert", ""),
+        ("deathray.breach.imminent", "The deathray caused a warp core breach."),
+        ("deathray.breach.cut_off", ""),
+        ("deathray.breach.boom", ""),
+        ("deathray.breach.stars", ""),
+        ("deathray.mutation.sulu", "The deathray turned the human crew into mutants."),
+        ("deathray.mutation.uhura", ""),
+        ("deathray.mutation.spock", ""),
+        ("deathray.mutation.vulcans", ""),
+        ("deathray.mutation.kirk", ""),
+        ("deathray.borg.readings", "The deathray opened a wormhole, and a Borg cube came through it."),
+        ("deathray.borg.wormhole", ""),
+        ("deathray.borg.cube", ""),
+        ("deathray.borg.size", ""),
+        ("deathray.borg.hailing", ""),
+        ("deathray.borg.message", ""),
+        ("deathray.tribbles", "The deathray is making tribbles."),
+        ("deathray.eldritch", "The deathray let in eldritch abominations."),
+        ("deathray.eldritch.plain", "The deathray let in eldritch abominations."),
+        ("deathray.inoperable", "The deathray is now inoperable."),
+
+        ("save.not_found", "Save file not found.\n"),
+        ("save.error", "\n{0}"),
+        ("save.wrong_password", "Wrong password."),
+        ("save.too_new", "That save is format version {0}; this game only reads up to version {1}."),
+        ("save.cant_create", "Unable to create that file."),
+        ("save.cant_write", "Unable to write to that file."),
+        ("save.created", "Saved to {0}."),
+        ("save.emergency_failed", "Unable to save."),
+    ],
+};
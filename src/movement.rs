@@ -1,8 +1,10 @@
-use rand::{Rng, thread_rng};
+use rand::Rng;
 use rand::prelude::SliceRandom;
 use crate::io::{get_args, get_yorn, slow_prout, SLOW, EXTRA_SLOW, wait};
 use crate::finish::DeathReason;
-use crate::{prout};
+use crate::constants::CLOAK_DETECTION;
+use crate::output::{emit, Message};
+use crate::{msg, prout, say};
 use crate::{input, io::abbrev};
 
 impl crate::structs::Universe {
@@ -13,19 +15,19 @@ impl crate::structs::Universe {
 
         // Check to make sure the drive the player wants to move isn't damaged or unusable
         if use_impulse && self.damage.impulse_drive > 3.5 {
-            prout!("[*Engineering*] Sir, the Impulse Drive is inoperable. We canna' use it.");
+            say!("movement.impulse.damaged");
             return
         } else if !use_impulse && self.damage.warp_drive > 2.0 {
-            prout!("[*Engineering*] The warp coils are smashed! Using it right now would blow the ship to smithereens!");
+            say!("movement.warp.smashed");
             return
         } else if !use_impulse && self.damage.warp_drive > 0.0 && self.warp_factor > 2.5 {
-            prout!("[*Engineering*] The warp engines are damaged, sir; I can only give you warp 2.5.");
+            say!("movement.warp.damaged");
             return
         }
          else if !use_impulse && self.cloaked {
-            prout!("[*Engineering*] We canna' use the warp drive while the claoking device is active!");
+            say!("movement.warp.cloaked");
             if self.damage.impulse_drive < 3.5 {
-                prout!("[*Engineering*] ... that said, I could probably give you impulse.");
+                say!("movement.warp.try_impulse");
             }
             return;
         }
@@ -33,7 +35,7 @@ impl crate::structs::Universe {
         let (mut dv, mut dh) = match angle {
             Some(x) => (-x.to_radians().sin(), x.to_radians().cos()),
             None => {
-                let raw = input("Direction: ");
+                let raw = input(&msg!("movement.direction"));
                 if raw.is_empty() {
                     return;
                 }
@@ -41,7 +43,7 @@ impl crate::structs::Universe {
                 match raw.parse::<f64>() {
                     Ok(x) => (-x.to_radians().sin(), x.to_radians().cos()),
                     Err(_) => {
-                        prout!("[*Helm*] That isn't an angle.");
+                        say!("movement.angle");
                         return;
                     }
                 }
@@ -57,7 +59,7 @@ impl crate::structs::Universe {
         let distance = match distance {
             Some(x) => x,
             None => {
-                let raw = input("Distance: ");
+                let raw = input(&msg!("movement.distance"));
                 if raw.is_empty() {
                     return;
                 }
@@ -65,34 +67,31 @@ impl crate::structs::Universe {
                 match raw.parse::<f64>() {
                     Ok(x) => x,
                     Err(_) => {
-                        prout!("[*Helm*] \"Second to the right and straight on till morning\" isn't a valid course.");
+                        say!("movement.invalid_distance");
                         return;
                     }
                 }
             }
         };
         if distance < 1.0 {
-            prout!("[*Helm*] Sir, we can't move that short of a distance.");
+            say!("movement.too_short");
             return;
         }
 
-        let power = match use_impulse {
-            false => 1.05 * self.warp_factor.powi(2) * (self.shield_status as u8 + 1) as f64 * (distance * bigger).round(),  // Shamelessly lifted from the Almy version
-            true => 20.0 + 100.0 * distance
-        };
+        let power = calc_power(&use_impulse, &distance, bigger, self.warp_factor, self.shield_status);
 
         if power >= self.energy {
-            prout!("[*Mr. Spock*] Captain, we do not have sufficient power to complete that manuever.");
+            say!("movement.no_power");
             if use_impulse {
-                prout!("According to my calculations, we can only go {:.2} quadrants before we run out of power.", (self.energy - 20.0) * 10.0);
+                say!("movement.no_power.range", format!("{:.2}", (self.energy - 20.0) * 10.0));
             }
             else if self.shield_status || (0.5*power) > self.energy {
                 let iwarp = (self.energy/(distance+0.05)).powf(1.0/3.0);
                 if iwarp > 0.0 {
-                    prout!("That said, we could do it at warp {}{}", iwarp, match self.shield_status {
-                        false => ".",
-                        true => ", provided we lower the shields."
-                    });
+                    match self.shield_status {
+                        false => say!("movement.no_power.warp", iwarp),
+                        true => say!("movement.no_power.warp_shields", iwarp),
+                    }
                 }
             }
             return;
@@ -103,13 +102,13 @@ impl crate::structs::Universe {
         if time > (self.time_remaining * 0.8) {  // Ask for confirmation if the trip takes more than 80% of the remaining time
             match use_impulse {
                 true => {
-                    prout!("[*Mr. Spock*] Captain, we can only go 0.95 sectors per stardate under impulse power. Are you sure we dare spend the time?");
+                    say!("movement.impulse.slow");
                     if !abbrev(&input("> ").to_lowercase(), "y", "yes") {
                         return
                     }
                 },
                 false => {
-                    prout!("[*Mr. Spock*] Sir, that would take us {:.2}% of our remaining time. Are you sure this is wise?", 100.0*time/self.time_remaining);
+                    say!("movement.warp.slow", format!("{:.2}", 100.0*time/self.time_remaining));
                     if !abbrev(&input("> ").to_lowercase(), "y", "yes") {
                         return
                     }
@@ -202,7 +201,7 @@ impl crate::structs::Universe {
                     0 => continue,
                     1 | 2 => {  // Neutral or inanimate object
                         interrupted = true;
-                        prout!("\nWARNING: Course blocked by object at sector {} {}", nsvert.round() as i32 + 1, nshoriz.round() as i32 + 1);
+                        emit(Message::new(format!("\nWARNING: Course blocked by object at sector {} {}", nsvert.round() as i32 + 1, nshoriz.round() as i32 + 1)).at(newloc).in_quadrant(nqvert as usize, nqhoriz as usize));
                         let stop_energy = 95.0 * self.warp_factor;
                         prout!("Emergency stop requires {} units of energy.", stop_energy);
                         self.energy -= stop_energy;
@@ -233,20 +232,21 @@ impl crate::structs::Universe {
         }
 
         if self.warp_factor > 6.0 && !use_impulse && !interrupted{
-            if ((6.0-self.warp_factor).powf(2.0) * (distance * bigger) / (2.0/3.0)) > rand::random() {
+            if ((6.0-self.warp_factor).powf(2.0) * (distance * bigger) / (2.0/3.0)) > self.rng.gen() {
                 // Whoopsies! The warp engine has been damaged.
-                self.damage.warp_drive += rand::random::<f64>() * distance / 10.0;
+                self.damage.warp_drive += self.rng.gen::<f64>() * distance / 10.0;
             }
 
-            if !interrupted && ((6.0-self.warp_factor).powf(2.3) * (distance * bigger) / (2.0/3.0)) > rand::random() {
-                let amount: f64 = if rand::random::<f64>() > 0.5 {
-                    rand::thread_rng().gen_range(-10.0..1.0)
+            if !interrupted && ((6.0-self.warp_factor).powf(2.3) * (distance * bigger) / (2.0/3.0)) > self.rng.gen() {
+                let amount: f64 = if self.rng.gen::<f64>() > 0.5 {
+                    self.rng.gen_range(-10.0..1.0)
                 } else {
-                    rand::thread_rng().gen_range(1.0..10.0)
+                    self.rng.gen_range(1.0..10.0)
                 };
 
                 self.time_remaining += amount;
                 self.stardate += amount;
+                crate::transcript::set_stardate(self.stardate);
             }
         }
 
@@ -275,8 +275,10 @@ impl crate::structs::Universe {
             return;
         }
 
+        self.romulan_detection(CLOAK_DETECTION);
+
         if self.get_quadrant().neutral_zone() && self.damage.radio == 0.0 {
-            prout!("\n[*Lt. Uhura*] Captain, a Romulan ship is hailing us. I'll put it on audio.");
+            say!("movement.romulan.hail");
             if self.ididit {
                 // The Romulans are royally pissed; skip the pleasantries.
                 slow_prout("*click* DIE, TREACHEROUS HUMAN SCUM!!!", SLOW, true);
@@ -300,8 +302,10 @@ impl crate::structs::Universe {
         self.qvert = new_qvert; self.qhoriz = new_qhoriz; self.sloc = new_sloc;  // Change the Enterprise's coords
         self.quadrants[self.qvert][self.qhoriz].sectors[self.sloc] = 8;  // Place Enterprise in new quadrant
 
+        self.romulan_detection(CLOAK_DETECTION);
+
         if self.get_quadrant().neutral_zone() && self.damage.radio == 0.0 {  // Check to see if the new quadrant is part of the Neutral Zone
-            prout!("\n[*Lt. Uhura*] Captain, a Romulan ship is hailing us. I'll put it on audio.");
+            say!("movement.romulan.hail");
             if self.ididit {
                 // The Romulans are royally pissed; skip the pleasantries.
                 slow_prout("*click* DIE, TREACHEROUS HUMAN SCUM!!!", SLOW, true);
@@ -327,9 +331,9 @@ impl crate::structs::Universe {
         self.quadrants[*nqvert as usize][*nqhoriz as usize].kill_entity(&nloc);
         self.kill_enemy(*nqvert as usize, *nqhoriz as usize, *nloc);
 
-        self.damage.add_ramming_damage(enemy_type);
+        self.damage.add_ramming_damage(enemy_type, &mut self.rng);
 
-        prout!("***Enemy ship at ({}, {}) destroyed in collision.", (nloc / 10) + 1, (nloc % 10) + 1);
+        emit(Message::new(format!("***Enemy ship at ({}, {}) destroyed in collision.", (nloc / 10) + 1, (nloc % 10) + 1)).at(*nloc).in_quadrant(*nqvert as usize, *nqhoriz as usize));
     }
 
     /// The player is attempting to leave the galaxy.
@@ -354,22 +358,21 @@ impl crate::structs::Universe {
     /// Change the ship's warp factor
     pub fn change_warp (&mut self, mut new_factor: f64) {
         if new_factor == f64::NEG_INFINITY {
-            new_factor = match input("New warp factor: ").parse::<f64>() {
+            new_factor = match input(&msg!("movement.warp.prompt")).parse::<f64>() {
                 Ok(f) => f,
                 Err(_) => {
-                    prout!("[*Helm*] Say again, sir?");
+                    say!("movement.warp.factor");
                     return
                 }
             }
         }
 
         if (new_factor <= 0.0) | (new_factor > 10.0) {
-            prout!("[*Engineering*] Do you think I'm God? I canna' change the laws of physics!");
-            prout!("[*Mr. Spock*] Captain, we can only go up to warp 10.");
+            say!("movement.warp.physics");
             return
         } else if new_factor > 6.0 {
             // Speeds greater than warp 6 risk damage to the warp engines.
-            if !get_yorn("[*Mr. Spock*] Sir, we'd risk damaging the warp engines at that speed. Are you sure the risk is worth it?\n> ") {
+            if !get_yorn(&msg!("movement.warp.risky")) {
                 return
             }
         }
@@ -381,11 +384,11 @@ impl crate::structs::Universe {
     /// Take a rest for a while.
     pub fn rest (&mut self, mut duration: f64) {
         if duration.is_nan() {
-            duration = match get_args::<f64>(input("\nHow much time would you like to skip? ")) {
+            duration = match get_args::<f64>(input(&msg!("movement.rest.prompt"))) {
                 Some (d) => match d.len() {
                     1 => d[0],
                     _ => {
-                        prout!("Huh?");
+                        say!("movement.rest.nonsense");
                         return
                     }
                 },
@@ -396,10 +399,10 @@ impl crate::structs::Universe {
         }
 
         if duration < 0.0 {
-            prout!("[*Mr. Spock*] Captain, need I remind you that under normal conditions we always go forwards in time?");
+            say!("movement.rest.negative");
             return
         } else if duration >= self.time_remaining {
-            if get_yorn("Captain, that would take more than our remaining time. Are you sure you wish to do this?\n> ") {
+            if get_yorn(&msg!("movement.rest.too_long")) {
                 self.death_reason = DeathReason::TimeUp;
             } else {
                 return;
@@ -415,17 +418,17 @@ impl crate::structs::Universe {
     /// Doesn't work if the subspace radio is damaged.
     pub fn call (&mut self) {
         if self.damage.radio > 1.8 {  // Can't send data
-            prout!("[*Lt. Uhura*] Captain, the subspace radio is inoperable.");
+            say!("movement.call.radio");
             return;
         } else if self.starbases == 0 {
-            slow_prout("[*Lt. Uhura*] I'm sorry captain... nobody's responding to our distress calls.", SLOW, true);
+            slow_prout(msg!("movement.call.no_answer"), SLOW, true);
             return;
         }
         let available = self.get_starbases();  // Since the compiler complains about temporary values otherwise
-        let selected = match available.choose(&mut rand::thread_rng()) {
+        let selected = match available.choose(&mut self.rng) {
             Some(s) => s,
             None => {
-                slow_prout("[*Lt. Uhura*] I'm sorry captain... nobody's responding to our distress calls.", SLOW, true);
+                slow_prout(msg!("movement.call.no_answer"), SLOW, true);
                 return;
             }
         };
@@ -433,7 +436,7 @@ impl crate::structs::Universe {
         if crate::DEBUG {
             prout!("Selected starbase: {:?}", &selected);
         }
-        if !get_yorn("[*Lt. Sulu*] Captain, are you sure this is a good idea?\n> ") {
+        if !get_yorn(&msg!("movement.call.confirm")) {
             return;
         }
 
@@ -446,10 +449,10 @@ impl crate::structs::Universe {
             }
         }
 
-        self.sloc = match unoccupied.choose(&mut rand::thread_rng()) {
+        self.sloc = match unoccupied.choose(&mut self.rng) {
             Some(s) => *s,
             None => {
-                slow_prout("[*Lt. Uhura*] Captain, a starbase received our distress call. The base commandant sends his condolences; he doesn't have any open births.", SLOW, true);
+                slow_prout(msg!("movement.call.full"), SLOW, true);
                 return;
             }
         };
@@ -462,33 +465,32 @@ impl crate::structs::Universe {
         //! Attempt to get away from a supernova.
 
         slow_prout("**AWHOOGAH**   **AWHOOGAH**", SLOW, true);
-        slow_prout("[*Comp.*] SUPERNOVA DETECTED", SLOW, true);
-        slow_prout("[*Comp.*] ENGAGING EMERGENCY ENGINE OVERRIDE", SLOW, true);
+        slow_prout(msg!("movement.supernova.detected"), SLOW, true);
+        slow_prout(msg!("movement.supernova.override"), SLOW, true);
         slow_prout("  ", EXTRA_SLOW, true);
 
         if self.damage.computer > 0.15 {
-            slow_prout("[*Comp.*] !ERROR!: CONTROL INTERLINKS INOPERABLE!", SLOW, true);
+            slow_prout(msg!("movement.supernova.interlinks"), SLOW, true);
             wait(5);
             prout!("******************* BOOM *******************");
             self.die(DeathReason::Supernova);
             return;
         }
 
-        let mut randint = thread_rng();
-        let dvert: i32 = randint.gen_range(-2..2);
-        let dhoriz = randint.gen_range(-2..2);
+        let dvert: i32 = self.rng.gen_range(-2..2);
+        let dhoriz = self.rng.gen_range(-2..2);
         
         let chosen_vert = self.qvert as i32 + dvert;
         let chosen_horiz = self.qhoriz as i32 + dhoriz;
 
         if chosen_vert < 0 || chosen_vert > 7 
           || chosen_horiz < 0 || chosen_horiz > 7 
-          || randint.gen::<f32>() < 0.2 
+          || self.rng.gen::<f32>() < 0.2 
           || !self.is_quadrant_accessible(chosen_vert as usize, chosen_horiz as usize){
-            slow_prout("[*Comp.*] OVERRIDE FAILED.", SLOW, false);
+            slow_prout(msg!("movement.supernova.failed"), SLOW, false);
 
-            if crate::DEBUG || randint.gen_range(0..5) == 0 {  // Continuing the self-aware computer joke
-                slow_prout(" SO LONG, AND THANKS FOR ALL THE FISH.", SLOW, false);
+            if crate::DEBUG || self.rng.gen_range(0..5) == 0 {  // Continuing the self-aware computer joke
+                slow_prout(msg!("movement.supernova.fish"), SLOW, false);
             }
 
             wait(7);  // Give the player some time to consider things
@@ -506,7 +508,7 @@ impl crate::structs::Universe {
         let mut new_sloc: usize = 0;
         let mut spot_found = false;
         while !spot_found {
-            new_sloc = randint.gen_range(0..100);
+            new_sloc = self.rng.gen_range(0..100);
             if self.get_other_quadrant(&(chosen_vert as usize), &(chosen_horiz as usize)).sector(&new_sloc) == 0 {
                 self.sloc = new_sloc;
                 spot_found = true;
@@ -518,13 +520,29 @@ impl crate::structs::Universe {
 }
 
 
+/// Calculate the amount of energy a move will take.
+/// 
+/// Arguments:
+/// - `use_impulse`: whether the ship is using its impulse drive
+/// - `distance`: the distance the ship is going
+/// - `bigger`: the larger of the course's vertical and horizontal components
+/// - `warp`: the warp factor. This value is ignored if `use_impulse` is true.
+/// - `shields`: whether the shields are up. This value is ignored if `use_impulse` is true.
+pub fn calc_power (use_impulse: &bool, distance: &f64, bigger: f64, warp: f64, shields: bool) -> f64 {
+    match use_impulse {
+        false => 1.05 * warp.powi(2) * (shields as u8 + 1) as f64 * (distance * bigger).round(),  // Shamelessly lifted from the Almy version
+        true => 20.0 + 100.0 * distance
+    }
+}
+
+
 /// Calculate the amount of time a move will take.
 /// 
 /// Arguments:
 /// - `use_impulse`: whether the ship is using its impulse drive
 /// - `distance`: the distance the ship is going
 /// - `warp`: the warp factor. This value is ignored if `use_impulse` is true.
-fn calc_time (use_impulse: &bool, distance: &f64, warp: f64) -> f64 {
+pub fn calc_time (use_impulse: &bool, distance: &f64, warp: f64) -> f64 {
     match use_impulse {
         true => distance * 0.55,
         false => distance / warp / 5.0
@@ -0,0 +1,423 @@
+//! Where the game's output goes.
+//!
+//! Nothing in the game prints directly. Everything (`prout!`, `say!`, `slow_prout`, the
+//! scans and reports) is turned into a `Message` and handed to the current `Output`,
+//! which is the terminal unless something else has been plugged in with `set_output`.
+//! `Memory` collects messages instead of printing them, for tests and bots.
+//!
+//! In plain mode (for screen readers, dumb terminals and pipes) the terminal leaves out
+//! colour codes and swaps box-drawing characters for ASCII ones.
+//!
+//! Slow text and pauses are scaled by the text speed, and the player can skip the rest
+//! of an animation by pressing any key. Until `set_speed` is called nothing is animated,
+//! so tests never sleep; nor is anything animated when the input isn't a terminal, since a
+//! piped run has nobody to watch it and the "keypress" would be the next command.
+
+use std::cell::{Cell, RefCell};
+use std::io::{stdin, stdout, Read, Write};
+use std::rc::Rc;
+
+use termion::color::{Fg, Red, Reset};
+use termion::raw::IntoRawMode;
+
+
+/// What sort of message it is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// Something happening, e.g. a torpedo hitting its target.
+    Narration,
+    /// A member of the crew speaking. The speaker is in `Message::speaker`.
+    Dialogue,
+    /// Something alarming, which the terminal shows in red.
+    Alert,
+    /// A question for the player. The answer is read separately.
+    Prompt,
+    /// Part of a sensor scan or chart. These are laid out already, so they aren't wrapped.
+    Scan,
+    /// Part of a report or table. Like scans, these aren't wrapped.
+    Report,
+}
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    pub kind: Kind,
+    /// Who's speaking, e.g. `Mr. Spock`.
+    pub speaker: Option<String>,
+    /// The text, without the speaker's tag.
+    pub text: String,
+    /// The quadrant it's about, one-indexed.
+    pub quadrant: Option<(usize, usize)>,
+    /// The sector it's about, one-indexed.
+    pub sector: Option<(usize, usize)>,
+    /// How long to pause after each character, in milliseconds, for dramatic effect.
+    pub delay: u64,
+    /// Whether the message finishes the line.
+    pub newline: bool,
+}
+
+impl Message {
+    /// Create a message, picking out the speaker from a leading `[*Speaker*]` tag.
+    pub fn new <T> (text: T) -> Message where T: ToString {
+        let text = text.to_string();
+        let body = text.trim_start_matches('\n');
+        let blank_lines = &text[..text.len() - body.len()];
+
+        let (kind, speaker, text) = match body.strip_prefix("[*").and_then(|b| b.split_once("*]")) {
+            Some((speaker, rest)) => (Kind::Dialogue, Some(String::from(speaker)), format!("{}{}", blank_lines, rest.trim_start())),
+            None => (Kind::Narration, None, text),
+        };
+
+        Message { kind, speaker, text, quadrant: None, sector: None, delay: 0, newline: true }
+    }
+
+    pub fn kind (mut self, kind: Kind) -> Message {
+        self.kind = kind;
+        self
+    }
+
+    /// Mark the message as being about a sector in the current quadrant.
+    ///
+    /// `loc`: The sector, as a zero-indexed `row * 10 + column`.
+    pub fn at (mut self, loc: usize) -> Message {
+        self.sector = Some((loc / 10 + 1, loc % 10 + 1));
+        self
+    }
+
+    /// Mark the message as being about a quadrant.
+    ///
+    /// `quadrant`: The zero-indexed row and column.
+    pub fn in_quadrant (mut self, row: usize, column: usize) -> Message {
+        self.quadrant = Some((row + 1, column + 1));
+        self
+    }
+
+    pub fn slow (mut self, delay: u64) -> Message {
+        self.delay = delay;
+        self
+    }
+
+    pub fn no_newline (mut self) -> Message {
+        self.newline = false;
+        self
+    }
+
+    /// The text as it should be shown, with the speaker's tag put back.
+    pub fn full_text (&self) -> String {
+        match &self.speaker {
+            Some(speaker) => {
+                let body = self.text.trim_start_matches('\n');
+                format!("{}[*{}*] {}", &self.text[..self.text.len() - body.len()], speaker, body)
+            },
+            None => self.text.clone(),
+        }
+    }
+}
+
+
+thread_local! {
+    static PLAIN: Cell<bool> = Cell::new(false);
+}
+
+/// Turn plain mode on or off.
+pub fn set_plain (plain: bool) {
+    PLAIN.with(|p| p.set(plain));
+}
+
+/// Whether the game is in plain mode.
+pub fn plain () -> bool {
+    PLAIN.with(|p| p.get())
+}
+
+/// How quickly slow text comes out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Speed {
+    Instant,
+    Fast,
+    Classic,
+}
+
+impl Speed {
+    /// Every speed, by name.
+    pub const ALL: &'static [(&'static str, Speed)] = &[("instant", Speed::Instant), ("fast", Speed::Fast), ("classic", Speed::Classic)];
+
+    pub fn from_name (name: &str) -> Option<Speed> {
+        Speed::ALL.iter().find(|s| s.0 == name).map(|s| s.1)
+    }
+
+    pub fn name (self) -> &'static str {
+        Speed::ALL.iter().find(|s| s.1 == self).unwrap().0
+    }
+
+    /// Scale a delay, in milliseconds, to this speed.
+    fn scale (self, delay: u64) -> u64 {
+        match self {
+            Speed::Instant => 0,
+            Speed::Fast => delay / 4,
+            Speed::Classic => delay,
+        }
+    }
+}
+
+thread_local! {
+    static SPEED: Cell<Speed> = Cell::new(Speed::Instant);
+    /// Whether the player has pressed a key to skip the animation. It's reset at the next prompt.
+    static SKIPPING: Cell<bool> = Cell::new(false);
+}
+
+pub fn set_speed (speed: Speed) {
+    SPEED.with(|s| s.set(speed));
+}
+
+pub fn speed () -> Speed {
+    SPEED.with(|s| s.get())
+}
+
+/// How long to actually wait instead of `delay` milliseconds, taking the speed and any skipping into account.
+fn animation_delay (delay: u64) -> u64 {
+    match SKIPPING.with(|s| s.get()) || !termion::is_tty(&stdin()) {
+        true => 0,
+        false => speed().scale(delay),
+    }
+}
+
+/// Wait for `delay` milliseconds, or until the player presses a key, in which case the rest of the animation is skipped.
+///
+/// The terminal must be in raw mode, or the keypress won't arrive until the player presses enter.
+fn wait_for_key (delay: u64) {
+    let mut fd = libc::pollfd { fd: libc::STDIN_FILENO, events: libc::POLLIN, revents: 0 };
+    if unsafe { libc::poll(&mut fd, 1, delay as libc::c_int) } > 0 {
+        let mut key = [0u8; 16];
+        let _ = stdin().read(&mut key);  // Throw the keypress away, so it doesn't end up in the next command
+        SKIPPING.with(|s| s.set(true));
+    }
+}
+
+/// Pause for `delay` milliseconds, for dramatic effect.
+pub fn pause (delay: u64) {
+    let delay = animation_delay(delay);
+    if delay == 0 {
+        return
+    }
+    let _raw = stdout().into_raw_mode();
+    wait_for_key(delay);
+}
+
+
+/// Box-drawing characters and what plain mode uses instead.
+const ASCII_BOXES: &[(char, char)] = &[
+    ('┏', '+'), ('┓', '+'), ('┗', '+'), ('┛', '+'),
+    ('━', '-'), ('─', '-'), ('┃', '|'),
+];
+
+/// Strip colour codes from text and replace box-drawing characters with ASCII.
+pub fn plain_text (text: &str) -> String {
+    let mut plain = String::new();
+    let mut escaped = false;
+    for c in text.chars() {
+        match (escaped, c) {
+            (false, '\x1b') => escaped = true,
+            (false, _) => plain.push(ASCII_BOXES.iter().find(|b| b.0 == c).map_or(c, |b| b.1)),
+            (true, 'm') => escaped = false,
+            (true, _) => {},
+        }
+    }
+    plain
+}
+
+
+/// Somewhere for messages to go.
+pub trait Output {
+    fn emit (&mut self, message: &Message);
+}
+
+
+/// Ranges of characters that take up two columns, e.g. CJK ideographs and emoji.
+const WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115F), (0x2E80, 0x303E), (0x3041, 0x33FF), (0x3400, 0x4DBF), (0x4E00, 0x9FFF),
+    (0xA000, 0xA4CF), (0xAC00, 0xD7A3), (0xF900, 0xFAFF), (0xFE30, 0xFE4F), (0xFF00, 0xFF60),
+    (0xFFE0, 0xFFE6), (0x1F300, 0x1F64F), (0x1F900, 0x1F9FF), (0x20000, 0x3FFFD),
+];
+
+/// Ranges of characters that take up no columns, e.g. combining accents.
+const ZERO_WIDTH: &[(u32, u32)] = &[
+    (0x0300, 0x036F), (0x1AB0, 0x1AFF), (0x1DC0, 0x1DFF), (0x200B, 0x200F),
+    (0x20D0, 0x20FF), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F),
+];
+
+/// How many columns a character takes up in the terminal.
+pub fn char_width (c: char) -> usize {
+    let c = c as u32;
+    if c < 0x20 || ZERO_WIDTH.iter().any(|r| r.0 <= c && c <= r.1) {
+        0
+    } else if WIDE.iter().any(|r| r.0 <= c && c <= r.1) {
+        2
+    } else {
+        1
+    }
+}
+
+/// How many columns text takes up in the terminal, not counting colour codes.
+pub fn display_width (text: &str) -> usize {
+    let mut width = 0;
+    let mut escaped = false;
+    for c in text.chars() {
+        match (escaped, c) {
+            (false, '\x1b') => escaped = true,
+            (false, _) => width += char_width(c),
+            (true, 'm') => escaped = false,
+            (true, _) => {},
+        }
+    }
+    width
+}
+
+
+/// Wrap text by whole words to fit in `width` columns.
+///
+/// Lines starting with a speaker's tag, like `[*Mr. Spock*] Captain...`, are wrapped with a
+/// hanging indent so that the speech lines up under itself rather than under the tag.
+pub fn wrap (text: &str, width: usize) -> String {
+    text.split('\n')
+        .map(|line| wrap_line(line, width))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn wrap_line (line: &str, width: usize) -> String {
+    let indent = match line.strip_prefix("[*").and_then(|l| l.find("*] ")) {
+        Some(end) => display_width(&line[..end + 5]),  // The tag plus the space after it
+        None => 0,
+    };
+    let indent = if indent * 2 > width { 0 } else { indent };  // A long-winded speaker on a narrow terminal just gets ordinary wrapping
+
+    let mut wrapped = String::new();
+    let mut column: usize = 0;
+    for (i, word) in line.split(' ').enumerate() {
+        let word_width = display_width(word);
+        if i > 0 {
+            if column + 1 + word_width > width && column > indent {
+                wrapped.push('\n');
+                wrapped.push_str(&" ".repeat(indent));
+                column = indent;
+            } else {
+                wrapped.push(' ');
+                column += 1;
+            }
+        }
+        wrapped.push_str(word);
+        column += word_width;
+    }
+    wrapped
+}
+
+
+/// Print messages to the terminal.
+pub struct Terminal;
+
+impl Output for Terminal {
+    fn emit (&mut self, message: &Message) {
+        let mut text = match message.kind {
+            Kind::Scan | Kind::Report | Kind::Prompt => message.full_text(),
+            _ => wrap(&message.full_text(), match termion::terminal_size() {
+                Ok(x) if x.0 > 0 => x.0 as usize,
+                _ => 80,  // If the terminal width is inaccessible, assume IBM standard 80 columns
+            }),
+        };
+        if plain() {
+            text = plain_text(&text);
+        } else if message.kind == Kind::Alert {
+            text = format!("{}{}{}", Fg(Red), text, Fg(Reset));
+        }
+
+        if animation_delay(message.delay) > 0 {
+            let _raw = stdout().into_raw_mode();
+            for c in text.chars() {
+                match c {
+                    '\n' => print!("\r\n"),  // Raw mode doesn't return the cursor by itself
+                    _ => print!("{}", c),
+                }
+                stdout().flush().unwrap();
+                let delay = animation_delay(message.delay);
+                if delay > 0 {
+                    wait_for_key(delay);
+                }
+            }
+        } else {
+            print!("{}", text);
+        }
+        if message.newline {
+            println!();
+        }
+        stdout().flush().unwrap();
+    }
+}
+
+
+/// Keep messages in memory instead of showing them.
+///
+/// Clones share the same messages, so keep one to read them after handing the other to `set_output`.
+#[derive(Clone, Default)]
+pub struct Memory {
+    pub messages: Rc<RefCell<Vec<Message>>>,
+}
+
+impl Memory {
+    /// Everything that's been said, as it would have been shown.
+    pub fn text (&self) -> String {
+        self.messages.borrow().iter()
+            .map(|m| m.full_text() + if m.newline { "\n" } else { "" })
+            .collect()
+    }
+
+    /// Take everything that's been said so far, line by line.
+    pub fn take (&self) -> Vec<String> {
+        let text = self.text();
+        self.messages.borrow_mut().clear();
+        text.lines().map(String::from).collect()
+    }
+}
+
+impl Output for Memory {
+    fn emit (&mut self, message: &Message) {
+        self.messages.borrow_mut().push(message.clone());
+    }
+}
+
+
+thread_local! {
+    static OUTPUT: RefCell<Box<dyn Output>> = RefCell::new(Box::new(Terminal));
+    /// Whether output is being captured by `capture`, and so shouldn't go in the transcript.
+    static CAPTURING: Cell<bool> = Cell::new(false);
+}
+
+/// Send everything to `output` from now on.
+///
+/// Returns the output that was being used before.
+pub fn set_output (output: Box<dyn Output>) -> Box<dyn Output> {
+    OUTPUT.with(|o| o.replace(output))
+}
+
+/// Run `f`, keeping what it says rather than showing it, and return it line by line.
+///
+/// It's for drawing things like the full-screen panels, so none of it goes in the transcript either.
+pub fn capture <F: FnOnce()> (f: F) -> Vec<String> {
+    let memory = Memory::default();
+    let previous = set_output(Box::new(memory.clone()));
+    let was_capturing = CAPTURING.with(|c| c.replace(true));
+    f();
+    CAPTURING.with(|c| c.set(was_capturing));
+    set_output(previous);
+    memory.take()
+}
+
+/// Send a message to the current output.
+pub fn emit (message: Message) {
+    if message.kind == Kind::Prompt {  // The animation's over once the player's asked for something
+        SKIPPING.with(|s| s.set(false));
+    }
+    if !CAPTURING.with(|c| c.get()) {
+        crate::transcript::record(&message);
+    }
+    OUTPUT.with(|o| o.borrow_mut().emit(&message));
+}
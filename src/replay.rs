@@ -0,0 +1,241 @@
+//! Recording games and playing them back.
+//!
+//! A recording holds the seed, difficulty, length and action times a game was started with, then every
+//! command and every answer to a question, in order. Since all the game's randomness comes
+//! from the seed, feeding the same input back in plays out exactly the same game. If the
+//! game panicked, that's recorded too, and the replay stops just before the fatal command.
+//!
+//! Passwords are never written down. The game's password is kept as a salted verifier (see
+//! `save::make_verifier`), so whoever replays the game has to know it, and answers to any
+//! question about a password are recorded as `secret` and asked for again during the replay.
+//!
+//! Recordings look like this:
+//!
+//! ```text
+//! seed 1701
+//! difficulty 2
+//! verifier 6f1c... 90ab...
+//! time 14
+//! actions 0.05 0.1 0.3
+//! > phasers
+//! ? automatic
+//! ? 500
+//! panic attempt to subtract with overflow
+//! ```
+//!
+//! Recordings from before the action times could be changed have no `actions` line, and use the defaults.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{stdin, Write};
+use std::path::Path;
+
+use crate::output::{self, Kind, Message, Speed};
+use crate::save;
+use crate::structs::{ActionTimes, Universe};
+use crate::{msg, say};
+
+
+/// A line of a recording.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Entry {
+    /// Something typed at the command prompt.
+    Command(String),
+    /// The answer to a question, e.g. `get_yorn` or how much energy to fire.
+    Answer(String),
+    /// The answer to a question about a password, which isn't recorded.
+    Secret,
+    /// The game panicked, with this message.
+    Panic(String),
+}
+
+impl Entry {
+    fn to_line (&self) -> String {
+        match self {
+            Entry::Command(c) => format!("> {}", c),
+            Entry::Answer(a) => format!("? {}", a),
+            Entry::Secret => String::from("secret"),
+            Entry::Panic(p) => format!("panic {}", p.replace('\n', " ")),
+        }
+    }
+
+    fn from_line (line: &str) -> Option<Entry> {
+        let rest = |prefix: char| line.strip_prefix(prefix).map(|l| String::from(l.strip_prefix(' ').unwrap_or(l)));
+        match line.chars().next() {
+            Some('>') => rest('>').map(Entry::Command),
+            Some('?') => rest('?').map(Entry::Answer),
+            _ if line == "secret" => Some(Entry::Secret),
+            _ => line.strip_prefix("panic ").map(|p| Entry::Panic(String::from(p))),
+        }
+    }
+}
+
+
+/// How a game started.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Header {
+    pub seed: u64,
+    pub difficulty: u8,
+    /// The game's password, as a verifier from `save::make_verifier`.
+    pub verifier: String,
+    /// How much time the game started with.
+    pub time: f64,
+    pub action_times: ActionTimes,
+}
+
+impl Header {
+    /// Whether `password` is the recorded game's password.
+    pub fn check_password (&self, password: &str) -> bool {
+        save::check_verifier(password, &self.verifier)
+    }
+
+    /// Create the universe the recorded game started with. The password should have been checked with `check_password`.
+    pub fn universe (&self, password: String) -> Universe {
+        let mut uni = Universe::with_seed(password, self.difficulty, self.seed);
+        uni.time_remaining = self.time;
+        uni.action_times = self.action_times;
+        uni
+    }
+}
+
+
+struct Replay {
+    entries: VecDeque<Entry>,
+    /// Whether to wait for the player before each command.
+    step: bool,
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<File>> = RefCell::new(None);
+    static REPLAY: RefCell<Option<Replay>> = RefCell::new(None);
+}
+
+
+/// Start recording a new game.
+pub fn start_recording (path: &Path, uni: &Universe) -> std::io::Result<()> {
+    let mut file = File::create(path)?;
+    writeln!(file, "seed {}\ndifficulty {}\nverifier {}\ntime {}", uni.seed, uni.get_difficulty(), save::make_verifier(&uni.password), uni.time_remaining)?;
+    let times = uni.action_times;
+    writeln!(file, "actions {} {} {}", times.torpedo, times.phaser, times.deathray)?;
+    RECORDING.with(|r| r.replace(Some(file)));
+    Ok(())
+}
+
+/// Add an entry to the recording, if there is one.
+pub fn record (entry: Entry) {
+    RECORDING.with(|r| {
+        if let Ok(mut r) = r.try_borrow_mut() {  // This is also called while panicking, so don't make things worse
+            if let Some(file) = r.as_mut() {
+                let _ = writeln!(file, "{}", entry.to_line());
+            }
+        }
+    });
+}
+
+
+/// Read a recording.
+pub fn load (path: &Path) -> Result<(Header, VecDeque<Entry>), String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let mut lines = contents.lines().peekable();
+
+    let mut field = |name: &str| -> Result<String, String> {
+        match lines.next().and_then(|l| l.strip_prefix(name)) {
+            Some(value) => Ok(String::from(value.trim_start_matches(' '))),
+            None => Err(msg!("replay.missing", name)),
+        }
+    };
+    let mut header = Header {
+        seed: field("seed")?.parse().map_err(|_| msg!("replay.missing", "seed"))?,
+        difficulty: field("difficulty")?.parse().map_err(|_| msg!("replay.missing", "difficulty"))?,
+        verifier: field("verifier")?,
+        time: field("time")?.parse().map_err(|_| msg!("replay.missing", "time"))?,
+        action_times: ActionTimes::default(),
+    };
+    let mut line_number = 5;
+    if let Some(actions) = lines.next_if(|l| l.starts_with("actions")) {
+        let times: Vec<f64> = actions.split_whitespace().skip(1).filter_map(|t| t.parse().ok()).collect();
+        match times[..] {
+            [torpedo, phaser, deathray] => header.action_times = ActionTimes { torpedo, phaser, deathray },
+            _ => return Err(msg!("replay.missing", "actions")),
+        }
+        line_number += 1;
+    }
+
+    let mut entries = VecDeque::new();
+    for (i, line) in lines.enumerate() {
+        match Entry::from_line(line) {
+            Some(e) => entries.push_back(e),
+            None => return Err(msg!("replay.bad_line", i + line_number, line)),
+        }
+    }
+    Ok((header, entries))
+}
+
+/// Start feeding a recording back in as the player's input.
+///
+/// `step`: Whether to wait for the player before each command, rather than playing at full speed.
+pub fn start_replay (entries: VecDeque<Entry>, step: bool) {
+    if !step {
+        output::set_speed(Speed::Instant);
+    }
+    REPLAY.with(|r| r.replace(Some(Replay { entries, step })));
+}
+
+pub fn replaying () -> bool {
+    REPLAY.with(|r| r.borrow().is_some())
+}
+
+fn stop_replay () {
+    REPLAY.with(|r| r.replace(None));
+}
+
+
+/// The next line of recorded input, if a replay is running.
+///
+/// `command`: Whether the game wants a command, rather than the answer to a question.
+///
+/// Returns `None` if there isn't a replay (any more), or if the player has to answer for themselves
+/// because the recorded answer was a secret.
+pub fn next_input (command: bool) -> Option<String> {
+    let (entry, after, step) = REPLAY.with(|r| {
+        let mut r = r.borrow_mut();
+        let replay = r.as_mut()?;
+        Some((replay.entries.pop_front(), replay.entries.front().cloned(), replay.step))
+    })?;
+
+    let line = match entry {
+        Some(Entry::Command(l)) | Some(Entry::Answer(l)) => Some(l),
+        Some(Entry::Secret) => None,
+        Some(Entry::Panic(p)) => {  // Panicked without any input to blame, e.g. while starting up
+            say!("replay.panicked.start", p);
+            stop_replay();
+            return None
+        },
+        None => {
+            say!("replay.finished");
+            stop_replay();
+            return None
+        }
+    };
+
+    if let Some(Entry::Panic(p)) = after {
+        say!("replay.panicked", line.unwrap_or_else(|| String::from("********")), p);
+        stop_replay();
+        return None
+    }
+
+    let line = line?;
+    if step && command {
+        output::emit(Message::new(msg!("replay.step", line)).kind(Kind::Prompt).no_newline());
+        let mut answer = String::new();
+        stdin().read_line(&mut answer).unwrap();
+        if answer.trim().eq_ignore_ascii_case("q") {
+            say!("replay.stopped");
+            stop_replay();
+            return None
+        }
+    }
+
+    Some(line)
+}
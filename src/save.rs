@@ -0,0 +1,197 @@
+//! The save file format.
+//!
+//! A save starts with a header line naming the format and its version, e.g. `SST-SAVE 1`.
+//! Saves from before there was a header are version 0. When the `Universe` (or anything in
+//! it) changes shape, bump `VERSION` and add a migration that turns the previous version's
+//! JSON into the new one; old saves are then brought up to date one version at a time.
+//!
+//! Since version 2 (version 3 only added the combat action times), the rest of the file is:
+//!
+//! ```text
+//! salt <hex>
+//! verifier <hex>
+//! <hex of the nonce followed by the encrypted JSON for the `Universe`>
+//! ```
+//!
+//! A key and a verifier are derived from the password and the salt with Argon2. The verifier
+//! is only there to tell a wrong password apart from a damaged file; the key encrypts the
+//! JSON with ChaCha20-Poly1305, so the save can't be read or edited without the password.
+//! Before version 2, the password was written out in the open, followed by a `\0` and the JSON.
+
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::{Rng, SeedableRng};
+use serde_json::Value;
+
+use crate::msg;
+use crate::structs::{ActionTimes, GameRng, Universe};
+
+
+/// What the header line starts with.
+pub const MAGIC: &str = "SST-SAVE";
+
+/// The version `freeze` writes.
+pub const VERSION: u32 = 3;
+
+/// Migrations from each version to the next: the first turns version 0 into version 1, and so on.
+const MIGRATIONS: &[fn(&mut Value)] = &[
+    add_seed,
+    |_| {},  // Version 2 encrypted the save, but the universe itself didn't change
+    add_action_times,
+];
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+
+/// Why a save couldn't be loaded.
+#[derive(Debug, PartialEq)]
+pub enum SaveError {
+    Corrupted,
+    WrongPassword,
+    /// Saved by a newer version of the game than this one.
+    TooNew(u32),
+}
+
+impl std::fmt::Display for SaveError {
+    fn fmt (&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SaveError::Corrupted => write!(f, "{}", msg!("save.corrupted")),
+            SaveError::WrongPassword => write!(f, "{}", msg!("save.wrong_password")),
+            SaveError::TooNew(v) => write!(f, "{}", msg!("save.too_new", v, VERSION)),
+        }
+    }
+}
+
+
+/// Turn a universe into the contents of a save file, encrypted with its password.
+pub fn encode (uni: &Universe) -> String {
+    let header = format!("{} {}", MAGIC, VERSION);
+    let salt: [u8; SALT_LEN] = rand::thread_rng().gen();
+    let nonce: [u8; NONCE_LEN] = rand::thread_rng().gen();
+    let (key, verifier) = derive(&uni.password, &salt);
+
+    let json = serde_json::to_string(uni).unwrap();
+    let sealed = ChaCha20Poly1305::new(Key::from_slice(&key))
+        .encrypt(Nonce::from_slice(&nonce), Payload { msg: json.as_bytes(), aad: header.as_bytes() })
+        .unwrap();
+
+    format!("{}\nsalt {}\nverifier {}\n{}{}", header, hex(&salt), hex(&verifier), hex(&nonce), hex(&sealed))
+}
+
+/// Read the contents of a save file, bringing it up to date if it's from an older version.
+pub fn decode (data: &str, password: &str) -> Result<Universe, SaveError> {
+    let (version, body) = match data.strip_prefix(MAGIC) {
+        Some(rest) => {
+            let (version, body) = rest.split_once('\n').ok_or(SaveError::Corrupted)?;
+            (version.trim().parse::<u32>().map_err(|_| SaveError::Corrupted)?, body)
+        },
+        None => (0, data),
+    };
+    if version > VERSION {
+        return Err(SaveError::TooNew(version))
+    }
+
+    let json = match version {
+        0 | 1 => {
+            let (saved_password, json) = body.split_once('\0').ok_or(SaveError::Corrupted)?;
+            if saved_password != password {
+                return Err(SaveError::WrongPassword)
+            }
+            String::from(json)
+        },
+        _ => decrypt(&format!("{} {}", MAGIC, version), body, password)?,
+    };
+
+    let mut value: Value = serde_json::from_str(&json).map_err(|_| SaveError::Corrupted)?;
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(&mut value);
+    }
+    serde_json::from_value(value).map_err(|_| SaveError::Corrupted)
+}
+
+
+/// Decrypt the body of a version 2 save.
+///
+/// `header`: The header line, which is authenticated along with the body so it can't be swapped out.
+fn decrypt (header: &str, body: &str, password: &str) -> Result<String, SaveError> {
+    let mut lines = body.lines();
+    let mut field = |name: &str| lines.next()
+        .and_then(|l| l.strip_prefix(name))
+        .and_then(|v| unhex(v.trim_start_matches(' ')))
+        .ok_or(SaveError::Corrupted);
+    let salt = field("salt")?;
+    let verifier = field("verifier")?;
+    let sealed = field("")?;
+    if sealed.len() < NONCE_LEN {
+        return Err(SaveError::Corrupted)
+    }
+
+    let (key, expected) = derive(password, &salt);
+    if !same(&verifier, &expected) {
+        return Err(SaveError::WrongPassword)
+    }
+
+    let (nonce, sealed) = sealed.split_at(NONCE_LEN);
+    let json = ChaCha20Poly1305::new(Key::from_slice(&key))
+        .decrypt(Nonce::from_slice(nonce), Payload { msg: sealed, aad: header.as_bytes() })
+        .map_err(|_| SaveError::Corrupted)?;  // The password was right, so it's been damaged or tampered with
+    String::from_utf8(json).map_err(|_| SaveError::Corrupted)
+}
+
+/// A salted verifier for a password, for files that need to check it without storing it: the salt and the verifier in hex.
+pub fn make_verifier (password: &str) -> String {
+    let salt: [u8; SALT_LEN] = rand::thread_rng().gen();
+    format!("{} {}", hex(&salt), hex(&derive(password, &salt).1))
+}
+
+/// Whether `password` is the one a verifier from `make_verifier` was made from.
+pub fn check_verifier (password: &str, verifier: &str) -> bool {
+    match verifier.split_once(' ').and_then(|(s, v)| Some((unhex(s)?, unhex(v)?))) {
+        Some((salt, verifier)) => same(&verifier, &derive(password, &salt).1),
+        None => false,
+    }
+}
+
+/// Compare two byte strings in constant time, so that how long it takes doesn't give away how much matched.
+fn same (a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Stretch a password into an encryption key and a verifier.
+fn derive (password: &str, salt: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut out = [0u8; 64];
+    Argon2::default().hash_password_into(password.as_bytes(), salt, &mut out).unwrap();
+    let (mut key, mut verifier) = ([0u8; 32], [0u8; 32]);
+    key.copy_from_slice(&out[..32]);
+    verifier.copy_from_slice(&out[32..]);
+    (key, verifier)
+}
+
+fn hex (bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn unhex (text: &str) -> Option<Vec<u8>> {
+    if text.len() % 2 != 0 {
+        return None
+    }
+    (0..text.len()).step_by(2).map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok()).collect()
+}
+
+
+/// Version 1 added the seed and the random number generator. Old games get a seed of 0 and a fresh generator.
+fn add_seed (value: &mut Value) {
+    if let Some(uni) = value.as_object_mut() {
+        uni.entry("seed").or_insert(Value::from(0u64));
+        uni.entry("rng").or_insert_with(|| serde_json::to_value(GameRng::from_entropy()).unwrap());
+    }
+}
+
+/// Version 3 made the time combat actions take configurable. Old games get the defaults.
+fn add_action_times (value: &mut Value) {
+    if let Some(uni) = value.as_object_mut() {
+        uni.entry("action_times").or_insert_with(|| serde_json::to_value(ActionTimes::default()).unwrap());
+    }
+}
@@ -1,31 +1,60 @@
-use crate::{structs::{EntityType, Alert}, prout};
+use crate::{structs::{EntityType, Alert}, msg, say};
+use crate::io::{abbrev, input};
+use crate::output::{self, emit, Kind, Message};
 use termion::color::{Blue, Fg, Green, Red, Reset, Yellow, LightBlue};
 
 
+/// The messages the plain description of a short-range scan uses for each sector code, for one sector and for several.
+const SRSCAN_NAMES: &[(u8, &str, &str)] = &[
+    (8, "scans.srscan.enterprise", "scans.srscan.enterprise"),
+    (3, "scans.srscan.klingon", "scans.srscan.klingons"),
+    (4, "scans.srscan.romulan", "scans.srscan.romulans"),
+    (6, "scans.srscan.tholian", "scans.srscan.tholians"),
+    (7, "scans.srscan.unknown", "scans.srscan.unknowns"),
+    (2, "scans.srscan.starbase", "scans.srscan.starbases"),
+    (5, "scans.srscan.black_hole", "scans.srscan.black_holes"),
+    (1, "scans.srscan.star", "scans.srscan.stars"),
+];
+
+
+/// Show a line of a scan or chart.
+fn scan <T> (line: T) where T: ToString {
+    emit(Message::new(line).kind(Kind::Scan));
+}
+
+/// Show a line of a report.
+fn report <T> (line: T) where T: ToString {
+    emit(Message::new(line).kind(Kind::Report));
+}
+
+/// A quadrant's Klingons, starbases and stars, as shown by `lrscan` and `starchart`.
+fn quadrant_summary (klingons: u8, starbases: u8, stars: u8) -> String {
+    format!("{}{}{}", match klingons > 0 {
+        true => format!("{}{}{}", Fg(Red), klingons, Fg(Reset)),
+        false => format!("{}{}{}", Fg(Green), klingons, Fg(Reset)),
+    }, match starbases > 0 {
+        true => format!("{}{}{}", Fg(Blue), starbases, Fg(Reset)),
+        false => starbases.to_string(),
+    }, stars)
+}
+
+
 impl crate::structs::Universe {
     pub fn srscan (&mut self) {
         //! Perform a short-range sensor scan
 
-        self.set_alert(Alert::Green);
-        let quad = self.get_quadrant();
-        if (quad.search(EntityType::Romulan).len() > 0)
-        || quad.search(EntityType::Tholian).len() > 0 
-        || quad.search(EntityType::Unknown).len() > 0
-        || self.on_life_reserve {
-            self.set_alert(Alert::Yellow);
-        }
-        if quad.search(EntityType::Klingon).len() > 0
-        || quad.search(EntityType::Romulan).len() > 0 && self.ididit {
-            self.set_alert(Alert::Red);
-        }
-
-        println!("    1 2 3 4 5 6 7 8 9 10");
-        println!("  ┏━━━━━━━━━━━━━━━━━━━━━┓");
+        self.update_alert();
 
         if self.damage.srsensors == 0.0 { // Chart quadrant, but only if the short-range sensors are undamaged.
             self.charted[self.qvert][self.qhoriz] = true;
         }
 
+        self.show_srscan();
+    }
+
+    /// Print what the short-range sensors can see, without scanning: nothing gets charted
+    /// and the alert level isn't updated. It's for displays, like the full-screen panel.
+    pub fn show_srscan (&self) {
         let mut viewable_coords: Vec<usize> = vec![self.sloc, self.sloc+10];
         if self.damage.srsensors > 0.0 {  // Limit the the player's vision to the Enterprise's immediate vicinity
             if self.sloc / 10 != 0 {
@@ -39,9 +68,11 @@ impl crate::structs::Universe {
             }
         }
 
+        let mut rows: Vec<String> = Vec::new();
+        let mut seen: Vec<(u8, usize)> = Vec::new();  // What's visible, for the plain description
         let mut index = 0;
         for vert in 0..10 {
-            print!("{}{}", vert+1, match vert {
+            let mut line = format!("{}{}", vert+1, match vert {
                 0..=8 => " ┃",
                 _ => "┃",
             });
@@ -56,7 +87,10 @@ impl crate::structs::Universe {
                 }
 
                 if printing {
-                    print!(" {}", match self.sector(&index) {
+                    if self.sector(&index) != 0 {
+                        seen.push((self.sector(&index), index));
+                    }
+                    line.push_str(&format!(" {}", match self.sector(&index) {
                         0 => String::from("."),
                         1 => String::from("*"),
                         2 => format!("{}B{}", Fg(Blue), Fg(Reset)),
@@ -73,19 +107,21 @@ impl crate::structs::Universe {
                             }
                         },
                         _ => panic!("It appears that the program has managed to put an impossible value in the sector srscan table. Please contact the developer with a bug report.")
-                    });
+                    }));
                 } else {
-                    print!("  ");
+                    line.push_str("  ");
                 }
 
                 index += 1;
             }
 
-            print!(" ┃");
+            line.push_str(" ┃");
+            rows.push(line);
+        }
 
-            match vert {
-                0 => println!(" Stardate:      {:.2}", self.stardate),
-                1 => println!(" Condition:     {}{}", match self.alert() {
+        let status: Vec<String> = (0..10).map(|vert| match vert {
+                0 => format!(" Stardate:      {:.2}", self.stardate),
+                1 => format!(" Condition:     {}{}", match self.current_alert() {
                     Alert::Red => format!("{}RED{}", Fg(Red), Fg(Reset)),
                     Alert::Yellow => format!("{}Yellow{}", Fg(Yellow), Fg(Reset)),
                     Alert::Green => format!("{}Green{}", Fg(Green), Fg(Reset)),
@@ -93,26 +129,186 @@ impl crate::structs::Universe {
                     true => "; cloaked",
                     false => "",
                 }),
-                2 => println!(" Position:      Sector {} {} of quadrant {} {}", self.sloc/10+1, self.sloc%10+1, &self.qvert+1, &self.qhoriz+1),
-                3 => println!(" Life Reserves: {}; reserves: {:.2} days", 
+                2 => format!(" Position:      Sector {} {} of quadrant {} {}", self.sloc/10+1, self.sloc%10+1, &self.qvert+1, &self.qhoriz+1),
+                3 => format!(" Life Reserves: {}; reserves: {:.2} days", 
                     match self.on_life_reserve {
                         false => String::from("Active"),
                         true => format!("{}OFFLINE{}", Fg(Red), Fg(Reset)),
                     }, self.life_reserves
                 ),
-                4 => println!(" Warp Factor:   {}", self.warp_factor),
-                5 => println!(" Energy:        {:.2}", self.energy),
-                6 => println!(" Torpedoes:     {}", self.torpedoes),
-                7 => println!(" Shields:       {}, {} energy remaining", match self.shield_status {
+                4 => format!(" Warp Factor:   {}", self.warp_factor),
+                5 => format!(" Energy:        {:.2}", self.energy),
+                6 => format!(" Torpedoes:     {}", self.torpedoes),
+                7 => format!(" Shields:       {}, {} energy remaining", match self.shield_status {
                     true => "UP",
                     false => "DOWN",
                 }, self.shields),
-                8 => println!(" Klingons:      {}", &self.klingons),
-                9 => println!(" Time Left:     {:.2}", self.time_remaining),
-                _ => println!()
+                8 => format!(" Klingons:      {}", &self.klingons),
+                9 => format!(" Time Left:     {:.2}", self.time_remaining),
+                _ => String::new()
+            }).collect();
+
+        if output::plain() {
+            self.describe_srscan(&seen);
+            for line in status {
+                scan(line.trim());
+            }
+            return
+        }
+
+        scan("    1 2 3 4 5 6 7 8 9 10");
+        scan("  ┏━━━━━━━━━━━━━━━━━━━━━┓");
+        for (row, status) in rows.iter().zip(status) {
+            scan(format!("{}{}", row, status));
+        }
+        scan("  ┗━━━━━━━━━━━━━━━━━━━━━┛");
+    }
+
+    /// Work out the alert level from what's in the quadrant.
+    pub fn update_alert (&mut self) {
+        let alert = self.current_alert();
+        self.set_alert(alert);
+    }
+
+    /// What the alert level should be, given what's in the quadrant.
+    pub fn current_alert (&self) -> Alert {
+        let quad = self.get_quadrant();
+        if quad.search(EntityType::Klingon).len() > 0
+        || quad.search(EntityType::Romulan).len() > 0 && self.ididit {
+            return Alert::Red
+        }
+        if (quad.search(EntityType::Romulan).len() > 0)
+        || quad.search(EntityType::Tholian).len() > 0 
+        || quad.search(EntityType::Unknown).len() > 0
+        || self.on_life_reserve {
+            return Alert::Yellow
+        }
+        Alert::Green
+    }
+
+    /// Print a full status report.
+    ///
+    /// Unlike the srscan sidebar, this doesn't depend on the short-range sensors.
+    pub fn report (&mut self) {
+        self.update_alert();
+
+        report("\n******** STATUS REPORT ********");
+        report(format!("* Stardate:          {:.2}", self.stardate));
+        report(format!("* Time left:         {:.2}", self.time_remaining));
+        report(format!("* Klingons left:     {}", self.klingons));
+        report(format!("* Starbases left:    {}", self.starbases));
+        report("*");
+        report(format!("* Energy:            {:.2}", self.energy));
+        report(format!("* Shields:           {}, {:.2} energy", match self.shield_status {
+            true => "UP",
+            false => "DOWN",
+        }, self.shields));
+        report(format!("* Torpedoes:         {}", self.torpedoes));
+        report(format!("* Crew:              {}", self.crew));
+        report(format!("* Life support:      {}; reserves: {:.2} days", match (self.on_life_reserve, self.damage.life_support > 0.0) {
+            (true, _) => format!("{}ON RESERVES{}", Fg(Red), Fg(Reset)),
+            (false, true) => format!("{}DAMAGED{}", Fg(Yellow), Fg(Reset)),
+            (false, false) => String::from("Active"),
+        }, self.life_reserves));
+        report(format!("* Condition:         {}{}{}", match self.alert() {
+            Alert::Red => format!("{}RED{}", Fg(Red), Fg(Reset)),
+            Alert::Yellow => format!("{}Yellow{}", Fg(Yellow), Fg(Reset)),
+            Alert::Green => format!("{}Green{}", Fg(Green), Fg(Reset)),
+        }, match self.docked {
+            true => "; docked",
+            false => "",
+        }, match self.cloaked {
+            true => "; cloaked",
+            false => "",
+        }));
+        report(format!("* Position:          Sector {} {} of quadrant {} {}", self.sloc/10+1, self.sloc%10+1, self.qvert+1, self.qhoriz+1));
+        report(format!("* Seed:              {}", self.seed));
+
+        let threatened: Vec<[usize; 2]> = self.get_threatened_starbases().into_iter()
+            .filter(|q| self.charted[q[0]][q[1]])
+            .collect();
+        if !threatened.is_empty() {
+            report("*");
+            report("* Starbases under threat:");
+            for q in threatened {
+                report(format!("*   Quadrant {} {}", q[0]+1, q[1]+1));
+            }
+        }
+    }
+
+    /// Look up a single status value for the `request` command.
+    ///
+    /// Returns the item's full name and its value, or `None` if the item doesn't exist.
+    pub fn request_value (&mut self, what: &str) -> Option<(&'static str, String)> {
+        let what = what.to_lowercase();
+        self.update_alert();
+
+        Some(match &what {
+            w if abbrev(w, "d", "date") || abbrev(w, "sta", "stardate") => ("stardate", format!("{:.2}", self.stardate)),
+            w if abbrev(w, "ti", "time") => ("time", format!("{:.2}", self.time_remaining)),
+            w if abbrev(w, "k", "klingons") => ("klingons", self.klingons.to_string()),
+            w if abbrev(w, "b", "bases") || abbrev(w, "starb", "starbases") => ("starbases", self.starbases.to_string()),
+            w if abbrev(w, "e", "energy") => ("energy", format!("{:.2}", self.energy)),
+            w if abbrev(w, "sh", "shields") => ("shields", format!("{} {:.2}", match self.shield_status {
+                true => "up",
+                false => "down",
+            }, self.shields)),
+            w if abbrev(w, "to", "torpedoes") => ("torpedoes", self.torpedoes.to_string()),
+            w if abbrev(w, "w", "warpfactor") => ("warpfactor", format!("{:.1}", self.warp_factor)),
+            w if abbrev(w, "cr", "crew") => ("crew", self.crew.to_string()),
+            w if abbrev(w, "l", "lsupport") => ("lsupport", format!("{} {:.2}", match self.on_life_reserve {
+                true => "reserves",
+                false => "active",
+            }, self.life_reserves)),
+            w if abbrev(w, "co", "condition") => ("condition", String::from(match self.alert() {
+                Alert::Red => "red",
+                Alert::Yellow => "yellow",
+                Alert::Green => "green",
+            }) + match (self.docked, self.cloaked) {
+                (true, _) => " docked",
+                (false, true) => " cloaked",
+                (false, false) => "",
+            }),
+            w if abbrev(w, "p", "position") => ("position", format!("{} {} {} {}", self.qvert+1, self.qhoriz+1, self.sloc/10+1, self.sloc%10+1)),
+            _ => return None
+        })
+    }
+
+    /// Print a single status value in a machine-readable `name: value` format.
+    pub fn request (&mut self, what: String) {
+        let what = match what.is_empty() {
+            true => input("Information desired? "),
+            false => what
+        };
+
+        match self.request_value(&what) {
+            Some((name, value)) => report(format!("{}: {}", name, value)),
+            None => {
+                say!("scans.request.unknown");
+            }
+        }
+    }
+
+    /// Describe what the short-range scan can see in words, for plain mode.
+    ///
+    /// `seen`: The sector code and location of everything visible, in order.
+    fn describe_srscan (&self, seen: &[(u8, usize)]) {
+        scan(msg!("scans.srscan.header", self.qvert+1, self.qhoriz+1));
+        if self.damage.srsensors > 0.0 {
+            scan(msg!("scans.srscan.damaged"));
+        }
+
+        for (code, singular, plural) in SRSCAN_NAMES {
+            let sectors: Vec<String> = seen.iter()
+                .filter(|(c, _)| c == code)
+                .map(|(_, loc)| format!("{} {}", loc/10+1, loc%10+1))
+                .collect();
+            match sectors.len() {
+                0 => {},
+                1 => scan(msg!(singular, sectors[0])),
+                _ => scan(msg!(plural, sectors.join(", "))),
             }
         }
-        println!("  ┗━━━━━━━━━━━━━━━━━━━━━┛");
     }
 
     pub fn lrscan (&mut self) {
@@ -122,80 +318,58 @@ impl crate::structs::Universe {
         //! information.
 
         if self.damage.lrsensors > 0.0 {
-            println!("[*Mr. Spock*] Sir, the long range sensors are inoperable due to damage.");
+            say!("scans.lrscan.damaged");
             return;
         }
 
-        println!("Long-range sensor scan for quadrant {} {}:", self.qvert+1, self.qhoriz+1);
+        scan(format!("Long-range sensor scan for quadrant {} {}:", self.qvert+1, self.qhoriz+1));
 
         for i in self.qvert as i32-1..=self.qvert as i32+1 {
+            let mut line = String::new();
             for j in self.qhoriz as i32-1..=self.qhoriz as i32 +1 {
                 if i<0 || i>7 || j<0 || j>7 {  // Galactic border
-                    print!("   -1");
+                    line.push_str("   -1");
                     continue; // Don't try to chart out-of-bounds areas.
                 }
                 
                 else if self.quadrants[i as usize][j as usize].is_supernova {  // Supernova
-                    print!(" 1000")
+                    line.push_str(" 1000")
                 } else {  // Regular quadrant
                     let (k, b, s) = self.quadrants[i as usize][j as usize].poll_lrscan();
-                    print!("  ");
-                    if k > 0 {
-                        print!("{}{}{}", Fg(Red), k, Fg(Reset));
-                    } else {
-                        print!("{}{}{}", Fg(Green), k, Fg(Reset));
-                    }
-
-                    if b > 0 {
-                        print!("{}{}{}", Fg(Blue), b, Fg(Reset));
-                    } else {
-                        print!("{}", b);
-                    }
-
-                    print!("{}", s);
+                    line.push_str("  ");
+                    line.push_str(&quadrant_summary(k, b, s));
                 }
                 self.charted[i as usize][j as usize] = true;
             }
-            println!();
+            scan(line);
         }
     }
 
     /// Print out a chart of the known galaxy.
     pub fn starchart (&self) {
-        println!("     1   2   3   4   5   6   7   8");
-        println!("  ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓");
+        scan("     1   2   3   4   5   6   7   8");
+        scan("  ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓");
 
         for vert in 0..8 {
-            print!("{} ┃ ", &vert+1);
+            let mut line = format!("{} ┃ ", &vert+1);
 
             for horiz in 0..8 {
                 if self.charted[vert][horiz] {
                     let (k,b,s) = self.quadrants[vert][horiz].poll_lrscan();
-
-                    if k > 0 {
-                        print!("{}{}{}", Fg(Red), k, Fg(Reset));
-                    } else {
-                        print!("{}{}{}", Fg(Green), k, Fg(Reset));
-                    }
-
-                    if b > 0 {
-                        print!("{}{}{}", Fg(Blue), b, Fg(Reset));
-                    } else {
-                        print!("{}", b);
-                    }
-
-                    print!("{} ", s);
+                    line.push_str(&quadrant_summary(k, b, s));
+                    line.push(' ');
                 } else {
-                    print!("{}???{} ", Fg(Yellow), Fg(Reset));
+                    line.push_str(&format!("{}???{} ", Fg(Yellow), Fg(Reset)));
                 }
             }
 
-            println!("┃");
+            line.push('┃');
+            scan(line);
             if vert < 7 {
-                prout!("  ┃                                 ┃");
+                scan("  ┃                                 ┃");
             }
         }
-        println!("  ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛");
+        scan("  ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛");
     }
 }
 
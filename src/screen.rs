@@ -0,0 +1,240 @@
+//! The full-screen interface.
+//!
+//! Rather than scrolling, this keeps the short-range scan (with its status sidebar), a
+//! mini star chart, the latest messages and the command line on the terminal's alternate
+//! screen. It's an `Output` like any other, so the rest of the game doesn't need to know
+//! it's there; the main loop just calls `update` after every command so the panels keep up.
+
+use std::io::{stdout, Stdout, Write};
+use std::sync::{Arc, Mutex, Weak};
+use std::{thread, time};
+
+use termion::color::{Fg, Red, Reset};
+use termion::screen::AlternateScreen;
+use termion::{clear, cursor};
+
+use crate::output::{self, char_width, display_width, wrap, Kind, Message, Output};
+use crate::structs::Universe;
+
+
+/// How many messages to keep for the log panel.
+const LOG_LENGTH: usize = 500;
+
+/// How often to check whether the terminal has been resized, in milliseconds.
+const RESIZE_POLL: u64 = 250;
+
+
+struct State {
+    /// The alternate screen. It's `None` once the screen has been closed.
+    out: Option<AlternateScreen<Stdout>>,
+    size: (u16, u16),
+    scan: Vec<String>,
+    chart: Vec<String>,
+    log: Vec<String>,
+    /// Whether the last line of the log is still being written to.
+    open_line: bool,
+    prompt: String,
+    /// Whether to draw in plain mode. The resize watcher runs on its own thread, so it can't ask `output::plain`.
+    plain: bool,
+}
+
+
+/// A handle to the full-screen interface. Clones share the same screen.
+#[derive(Clone)]
+pub struct Screen {
+    state: Arc<Mutex<State>>,
+}
+
+impl Screen {
+    /// Switch to the alternate screen.
+    ///
+    /// Returns `None` if stdout isn't a terminal, in which case the game should carry on scrolling.
+    pub fn new () -> Option<Screen> {
+        if !termion::is_tty(&stdout()) {
+            return None
+        }
+        let size = termion::terminal_size().ok()?;
+
+        let screen = Screen { state: Arc::new(Mutex::new(State {
+            out: Some(AlternateScreen::from(stdout())),
+            size,
+            scan: Vec::new(),
+            chart: Vec::new(),
+            log: Vec::new(),
+            open_line: false,
+            prompt: String::new(),
+            plain: output::plain(),
+        })) };
+        screen.state.lock().unwrap().draw();
+
+        let watched: Weak<Mutex<State>> = Arc::downgrade(&screen.state);
+        thread::spawn(move || watch_size(watched));
+
+        Some(screen)
+    }
+
+    /// Bring the scan and chart panels up to date.
+    pub fn update (&self, uni: &Universe) {
+        let scan = output::capture(|| uni.show_srscan());  // Not `srscan`, which would chart the quadrant as if the player had scanned it
+        let chart: Vec<String> = output::capture(|| uni.starchart()).into_iter()
+            .filter(|line| !line.trim_matches(|c| c == ' ' || c == '┃' || c == '|').is_empty())  // Squash the gaps between rows
+            .collect();
+
+        let mut state = self.state.lock().unwrap();
+        state.scan = scan;
+        state.chart = chart;
+        state.draw();
+    }
+
+    /// Leave the alternate screen.
+    ///
+    /// The last screenful of messages is printed again afterwards, so the end of the game isn't lost.
+    pub fn close (&self) {
+        let mut state = self.state.lock().unwrap();
+        state.out = None;
+        let height = state.size.1 as usize;
+        for line in &state.log[state.log.len().saturating_sub(height)..] {
+            println!("{}", line);
+        }
+    }
+}
+
+impl Output for Screen {
+    fn emit (&mut self, message: &Message) {
+        let mut state = self.state.lock().unwrap();
+
+        if message.kind == Kind::Prompt {
+            state.prompt = String::from(message.full_text().rsplit('\n').next().unwrap());
+            state.draw();
+            return
+        }
+        state.prompt.clear();
+
+        let width = state.size.0 as usize;
+        let text = match message.kind {
+            Kind::Scan | Kind::Report => message.full_text(),
+            _ => wrap(&message.full_text(), width),
+        };
+        for (i, line) in text.split('\n').enumerate() {
+            let line = match message.kind {
+                Kind::Alert => format!("{}{}{}", Fg(Red), line, Fg(Reset)),
+                _ => String::from(line),
+            };
+            match (i, state.open_line, state.log.last_mut()) {
+                (0, true, Some(last)) => last.push_str(&line),
+                _ => state.log.push(line),
+            }
+        }
+        state.open_line = !message.newline;
+        if state.log.len() > LOG_LENGTH {
+            let excess = state.log.len() - LOG_LENGTH;
+            state.log.drain(..excess);
+        }
+
+        state.draw();
+        drop(state);
+        output::pause(message.delay * text.chars().count() as u64);  // Keep the pacing of slow messages, even though they appear all at once
+    }
+}
+
+
+impl State {
+    /// Redraw everything.
+    fn draw (&mut self) {
+        let (width, height) = (self.size.0 as usize, self.size.1 as usize);
+        let mut rows: Vec<String> = Vec::new();
+
+        // The scan goes on the left, with the chart beside it if there's room, or underneath if not.
+        let scan_width = self.scan.iter().map(|l| display_width(l)).max().unwrap_or(0);
+        let chart_width = self.chart.iter().map(|l| display_width(l)).max().unwrap_or(0);
+        if scan_width + 2 + chart_width <= width {
+            for i in 0..self.scan.len().max(self.chart.len()) {
+                let left = self.scan.get(i).map_or("", |l| l.as_str());
+                let right = self.chart.get(i).map_or("", |l| l.as_str());
+                rows.push(format!("{}{}  {}", left, " ".repeat(scan_width - display_width(left)), right));
+            }
+        } else {
+            rows.extend(self.scan.iter().cloned());
+            if rows.len() + self.chart.len() + 6 <= height {  // Otherwise there'd be hardly any room for messages
+                rows.extend(self.chart.iter().cloned());
+            }
+        }
+        if !rows.is_empty() {
+            rows.push("─".repeat(width));
+        }
+
+        // The messages fill the rest, leaving the last two rows for the command line and for the
+        // cursor to fall onto when the player presses enter.
+        let log_height = height.saturating_sub(rows.len() + 2);
+        let start = self.log.len().saturating_sub(log_height);
+        rows.extend(self.log[start..].iter().cloned());
+
+        let out = match &mut self.out {
+            Some(o) => o,
+            None => return
+        };
+        write!(out, "{}{}", clear::All, cursor::Goto(1, 1)).unwrap();
+        for (i, row) in rows.iter().take(height.saturating_sub(2)).enumerate() {
+            let row = match self.plain {
+                true => output::plain_text(row),
+                false => row.clone(),
+            };
+            write!(out, "{}{}", cursor::Goto(1, i as u16 + 1), clip(&row, width)).unwrap();
+        }
+        write!(out, "{}{}", cursor::Goto(1, height.saturating_sub(1).max(1) as u16), clip(&self.prompt, width)).unwrap();
+        out.flush().unwrap();
+    }
+}
+
+
+/// Redraw the screen whenever the terminal changes size, until the screen goes away.
+fn watch_size (state: Weak<Mutex<State>>) {
+    loop {
+        thread::sleep(time::Duration::from_millis(RESIZE_POLL));
+        let state = match state.upgrade() {
+            Some(s) => s,
+            None => return
+        };
+        let mut state = state.lock().unwrap();
+        if state.out.is_none() {
+            return
+        }
+        if let Ok(size) = termion::terminal_size() {
+            if size != state.size {
+                state.size = size;
+                state.draw();
+            }
+        }
+    }
+}
+
+
+/// Cut a line down to `width` columns, keeping its colour codes intact.
+pub fn clip (line: &str, width: usize) -> String {
+    if display_width(line) <= width {
+        return String::from(line)
+    }
+
+    let mut clipped = String::new();
+    let mut shown = 0;
+    let mut escaped = false;
+    for c in line.chars() {
+        match (escaped, c) {
+            (false, '\x1b') => escaped = true,
+            (false, _) => {
+                if shown + char_width(c) > width {
+                    shown = width;  // Don't let anything narrower sneak in after a wide character that didn't fit
+                    continue;
+                }
+                shown += char_width(c);
+            },
+            (true, 'm') => escaped = false,
+            (true, _) => {},
+        }
+        clipped.push(c);
+    }
+    if line.contains('\x1b') {
+        clipped.push_str(&format!("{}", Fg(Reset)));  // Don't let a colour run on past the edge
+    }
+    clipped
+}
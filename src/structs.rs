@@ -1,14 +1,39 @@
 //! Contains all the various data structs used by the program.
 use core::fmt;
 
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use serde::{Serialize, Deserialize};
 use crate::{finish::DeathReason, io::SLOW, slow_prout};
 use crate::prout;
 use crate::damage::Damage;
+use crate::constants::{DAYS_PER_LENGTH, DEATHRAY_TIME, KLINGON_KO_TIME, PHASER_TIME, TORPEDO_TIME};
 
 
 
+/// The random number generator behind everything that happens by chance.
+///
+/// It lives on the `Universe` and is saved along with it, so that the same seed and the same
+/// commands always play out the same way.
+pub type GameRng = ChaCha8Rng;
+
+
+/// How many stardates each combat action takes. The defaults are in `constants`; they can be changed for a new game with command-line options.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct ActionTimes {
+    /// For each torpedo fired.
+    pub torpedo: f64,
+    pub phaser: f64,
+    pub deathray: f64,
+}
+
+impl Default for ActionTimes {
+    fn default () -> ActionTimes {
+        ActionTimes { torpedo: TORPEDO_TIME, phaser: PHASER_TIME, deathray: DEATHRAY_TIME }
+    }
+}
+
+
 /// The main data struct. It encapsulates everything else.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Universe {
@@ -48,11 +73,23 @@ pub struct Universe {
 
     pub warp_factor: f64,
     pub cloaked: bool,
+
+    /// The seed the game was started with. Saves from before there were seeds get 0 and a random generator (see `save::add_seed`).
+    pub seed: u64,
+    pub rng: GameRng,
+    /// Saves from before these could be changed get the defaults (see `save::add_action_times`).
+    pub action_times: ActionTimes,
 }
 
 impl Universe {
+    /// Start a game with a random seed.
     pub fn new (password: String, difficulty: u8) -> Universe {
-        let mut randint = rand::thread_rng();
+        Universe::with_seed(password, difficulty, rand::random())
+    }
+
+    /// Start a game from a particular seed. The same seed always creates the same galaxy.
+    pub fn with_seed (password: String, difficulty: u8, seed: u64) -> Universe {
+        let mut randint = GameRng::seed_from_u64(seed);
         let starbases: u32 = randint.gen_range(3..8);
 
         let mut to_return = Universe {
@@ -66,7 +103,7 @@ impl Universe {
             leave_attempts: 0,
             difficulty,
 
-            stardate: (100.0f64*(31.0*rand::random::<f64>()+20.0)) as f64,
+            stardate: (100.0f64*(31.0*randint.gen::<f64>()+20.0)) as f64,
             time_remaining: 0.0,
 
             quadrants: <[[Quadrant; 8]; 8]>::default(),
@@ -90,17 +127,21 @@ impl Universe {
             warp_factor: 4.0,
             shield_status: false,
             cloaked: false,
+
+            seed,
+            rng: GameRng::seed_from_u64(seed),  // Replaced once the galaxy has been created
+            action_times: ActionTimes::default(),
         };
 
         to_return.quadrants[to_return.qvert][to_return.qhoriz].sectors[to_return.sloc] = 8;
 
         for i in 0..8 {
             for j in 0..8 {
-                to_return.klingons += to_return.quadrants[i][j].init(difficulty);
+                to_return.klingons += to_return.quadrants[i][j].init(difficulty, &mut randint);
             }
         }
 
-        to_return.time_remaining = 14f64; // TODO: Implement an algorithm if I decide to have different game lengths
+        to_return.set_length(2);
 
         let mut starbased: Vec<(usize, usize)> = Vec::new();
         let mut i: u32 = 0;
@@ -110,7 +151,7 @@ impl Universe {
             match starbased.contains(&(vert, horiz)) {
                 true => continue,
                 false => {
-                    to_return.quadrants[vert][horiz].add_starbase();
+                    to_return.quadrants[vert][horiz].add_starbase(&mut randint);
                     to_return.charted[vert][horiz] = true;
                     starbased.push((vert,horiz)); 
                     i+=1;
@@ -121,6 +162,7 @@ impl Universe {
         std::mem::drop(starbased);
         std::mem::drop(i);  // In case I want to use it later on
 
+        to_return.rng = randint;
         return to_return
     }
 
@@ -130,6 +172,11 @@ impl Universe {
     }
 
 
+    /// Set how long the game lasts: 1 for short, 2 for medium or 4 for long, as in the original.
+    pub fn set_length (&mut self, length: u8) {
+        self.time_remaining = DAYS_PER_LENGTH * length as f64;
+    }
+
     pub fn get_difficulty (&self) -> u8 {
         return self.difficulty
     }
@@ -226,7 +273,9 @@ impl Universe {
     pub fn add_time (&mut self, diff: f64) {
         self.time_remaining -= diff;
         self.stardate += diff;
+        crate::transcript::set_stardate(self.stardate);
         self.damage.repair(diff, self.docked);
+        self.run_cloak(diff);
     }
 
 
@@ -243,7 +292,7 @@ impl Universe {
             EntityType::Klingon => {
                 self.score.kill_klingon();
                 self.klingons -= 1;
-                self.time_remaining += 1.5;
+                self.time_remaining += KLINGON_KO_TIME;
             },
             EntityType::Romulan => self.score.kill_romulan(),
             EntityType::Tholian => self.score.kill_tholian(),
@@ -283,10 +332,9 @@ impl Default for Quadrant {
 }
 
 impl Quadrant {
-    fn init (&mut self, difficulty: u8) -> u32 {
+    fn init (&mut self, difficulty: u8, randint: &mut GameRng) -> u32 {
         //! Initialize the quadrant
 
-        let mut randint = rand::thread_rng();
         let mut klingons = 0;
         let mut romulans = 0;
         let stars = 0;
@@ -359,10 +407,9 @@ impl Quadrant {
         return klingons as u32
     }
 
-    fn add_starbase(&mut self) {
+    fn add_starbase(&mut self, randint: &mut GameRng) {
         //! Add a starbase to the quadrant
 
-        let mut randint = rand::thread_rng();
 
         loop {
            let location = randint.gen_range(0..100);
@@ -0,0 +1,112 @@
+//! Session transcripts.
+//!
+//! While a transcript is being kept, every prompt, everything the player types and every
+//! line of output is appended to a file, each stamped with the stardate. It's meant for bug
+//! reports, so that there's a record of what led up to a crash.
+//!
+//! `output::emit` and `io::input` feed everything in here; nothing else needs to know about it.
+
+use std::cell::{Cell, RefCell};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::output::{plain_text, Kind, Message};
+
+
+struct Transcript {
+    file: File,
+    path: PathBuf,
+    /// Output that hasn't finished its line yet, e.g. a torpedo's track.
+    pending: String,
+}
+
+thread_local! {
+    static TRANSCRIPT: RefCell<Option<Transcript>> = RefCell::new(None);
+    /// The stardate to stamp lines with. It's `None` until the game has started.
+    static STARDATE: Cell<Option<f64>> = Cell::new(None);
+}
+
+
+/// Start keeping a transcript, adding to the end of the file if it already exists.
+pub fn start (path: &Path) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "# Super Star Trek transcript")?;
+    TRANSCRIPT.with(|t| t.replace(Some(Transcript { file, path: path.to_path_buf(), pending: String::new() })));
+    Ok(())
+}
+
+/// Stop keeping a transcript.
+///
+/// Returns `false` if there wasn't one.
+pub fn stop () -> bool {
+    TRANSCRIPT.with(|t| t.borrow_mut().take()).is_some()
+}
+
+/// Where the transcript is going, if there is one.
+pub fn path () -> Option<PathBuf> {
+    TRANSCRIPT.with(|t| t.borrow().as_ref().map(|t| t.path.clone()))
+}
+
+pub fn set_stardate (stardate: f64) {
+    STARDATE.with(|s| s.set(Some(stardate)));
+}
+
+
+/// Write a line to the transcript, if there is one.
+///
+/// `marker`: What sort of line it is: `?` for a prompt, `>` for what the player typed, or a space for output.
+fn write_line (marker: char, line: &str) {
+    let stamp = match STARDATE.with(|s| s.get()) {
+        Some(s) => format!("{:>8.2}", s),
+        None => String::from("        "),
+    };
+    TRANSCRIPT.with(|t| {
+        if let Some(t) = t.borrow_mut().as_mut() {
+            let _ = writeln!(t.file, "{} {} {}", stamp, marker, line);  // A broken transcript isn't worth interrupting the game over
+        }
+    });
+}
+
+/// Record a message.
+pub fn record (message: &Message) {
+    if path().is_none() {
+        return
+    }
+
+    let text = plain_text(&message.full_text());
+    let text = TRANSCRIPT.with(|t| {
+        let mut t = t.borrow_mut();
+        let t = t.as_mut().unwrap();
+        let text = std::mem::take(&mut t.pending) + &text;
+        if message.newline || message.kind == Kind::Prompt {
+            Some(text)
+        } else {
+            t.pending = text;
+            None
+        }
+    });
+
+    if let Some(text) = text {
+        let marker = match message.kind {
+            Kind::Prompt => '?',
+            _ => ' ',
+        };
+        let text = match message.kind {
+            Kind::Prompt => text.trim_start_matches('\n'),
+            _ => &text,
+        };
+        for line in text.split('\n') {
+            write_line(marker, line);
+        }
+    }
+}
+
+/// Record what the player typed in answer to `prompt`.
+pub fn record_input (prompt: &str, line: &str) {
+    if crate::io::is_secret(prompt) {  // Transcripts get attached to bug reports, so keep passwords out of them
+        write_line('>', "********");
+    } else {
+        write_line('>', line);
+    }
+}